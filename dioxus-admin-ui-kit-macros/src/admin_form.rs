@@ -0,0 +1,285 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Path};
+
+use crate::form_field::{FieldKind, FormField};
+
+pub fn generate(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &ast.ident;
+
+    let dto = parse_dto(ast)?;
+
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "AdminForm can be derived only for structs",
+        ));
+    };
+
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "AdminForm supports only structs with named fields",
+        ));
+    };
+
+    let mut fields = Vec::new();
+
+    for field in named.named.iter() {
+        fields.push(FormField::parse(field)?);
+    }
+
+    let new_fields = fields.iter().map(generate_new_field);
+    let dto_fields = fields.iter().map(generate_dto_field);
+    let registered_fields = fields.iter().filter_map(generate_register_field);
+    let registered_fields_mut = fields.iter().filter_map(generate_register_field_mut);
+    let rendered_fields = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| generate_render_field(index, field));
+    let rendered_elements = (0..fields.len()).map(|index| {
+        let element = format_ident!("field_{}", index);
+        quote! { {#element} }
+    });
+
+    let result = quote! {
+        impl #struct_name {
+            pub fn new(dto: &#dto) -> Self {
+                Self {
+                    #(#new_fields)*
+                }
+            }
+
+            pub fn as_form(&self) -> ::dioxus_admin_ui_kit::types::FormRef<'_> {
+                ::dioxus_admin_ui_kit::types::FormRef::new()
+                    #(#registered_fields)*
+            }
+
+            pub fn as_form_mut(&mut self) -> ::dioxus_admin_ui_kit::types::FormMut<'_> {
+                ::dioxus_admin_ui_kit::types::FormMut::new()
+                    #(#registered_fields_mut)*
+            }
+
+            pub fn is_valid(&self) -> bool {
+                self.as_form().is_valid()
+            }
+
+            pub fn get_errors(&self) -> ::dioxus_admin_ui_kit::types::FieldErrors {
+                self.as_form().get_errors()
+            }
+
+            pub fn mark_touched(&mut self) {
+                self.as_form_mut().mark_touched();
+            }
+
+            pub fn to_dto(&self) -> Result<#dto, ::dioxus_admin_ui_kit::types::FieldErrors> {
+                let errors = self.get_errors();
+
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+
+                Ok(#dto {
+                    #(#dto_fields)*
+                })
+            }
+
+            /// Like `to_dto`, but marks all fields touched first, so blank required ones
+            /// show their "required" message after a failed attempt.
+            pub fn try_to_dto(&mut self) -> Result<#dto, ::dioxus_admin_ui_kit::types::FieldErrors> {
+                self.mark_touched();
+                self.to_dto()
+            }
+
+            /// Passes the DTO to `on_submit` if all fields are valid, see `try_to_dto`.
+            pub fn attempt_submit<TResult>(
+                &mut self,
+                on_submit: impl FnOnce(#dto) -> TResult,
+            ) -> Result<TResult, ::dioxus_admin_ui_kit::types::FieldErrors> {
+                let dto = self.try_to_dto()?;
+                Ok(on_submit(dto))
+            }
+
+            pub fn render(
+                form: ::dioxus_admin_ui_kit::macro_support::dioxus::prelude::Signal<Self>,
+            ) -> ::dioxus_admin_ui_kit::macro_support::dioxus::prelude::Element {
+                use ::dioxus_admin_ui_kit::macro_support::dioxus::prelude::*;
+
+                let value = form.read();
+
+                #(#rendered_fields)*
+
+                rsx! {
+                    #(#rendered_elements)*
+                }
+            }
+        }
+    };
+
+    Ok(result)
+}
+
+fn parse_dto(ast: &DeriveInput) -> syn::Result<Path> {
+    let mut result = None;
+
+    for attr in ast.attrs.iter() {
+        if !attr.path().is_ident("admin_form") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("dto") {
+                result = Some(meta.value()?.parse::<Path>()?);
+                return Ok(());
+            }
+
+            Err(meta.error("expected `dto`"))
+        })?;
+    }
+
+    match result {
+        Some(result) => Ok(result),
+        None => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "AdminForm requires #[admin_form(dto = DtoType)] attribute",
+        )),
+    }
+}
+
+fn generate_new_field(field: &FormField) -> TokenStream {
+    let name = field.name;
+    let dto_field = &field.dto_field;
+
+    match field.kind {
+        FieldKind::InputValue => quote! {
+            #name: ::dioxus_admin_ui_kit::types::InputValue::new(dto.#dto_field.clone()),
+        },
+        FieldKind::InputValueOpt => quote! {
+            #name: ::dioxus_admin_ui_kit::types::InputValueOpt::new(dto.#dto_field.clone()),
+        },
+        FieldKind::SelectEnumValueOpt => quote! {
+            #name: ::dioxus_admin_ui_kit::types::SelectEnumValueOpt::new(dto.#dto_field.clone()),
+        },
+        FieldKind::Bool => quote! {
+            #name: dto.#dto_field,
+        },
+        FieldKind::BoolValue => quote! {
+            #name: ::dioxus_admin_ui_kit::types::BoolValue::new(dto.#dto_field),
+        },
+    }
+}
+
+fn generate_dto_field(field: &FormField) -> TokenStream {
+    let name = field.name;
+    let dto_field = &field.dto_field;
+    let field_name = field.get_field_name();
+
+    match field.kind {
+        FieldKind::InputValue => quote! {
+            #dto_field: self.#name.get_value().ok_or_else(|| {
+                let mut errors = ::dioxus_admin_ui_kit::types::FieldErrors::new();
+                errors.insert(
+                    #field_name,
+                    ::dioxus_admin_ui_kit::macro_support::ValueValidationResult::IllegalChars,
+                );
+                errors
+            })?,
+        },
+        FieldKind::InputValueOpt => quote! {
+            #dto_field: self.#name.get_value(),
+        },
+        FieldKind::SelectEnumValueOpt => quote! {
+            #dto_field: self.#name.get_value().cloned(),
+        },
+        FieldKind::Bool => quote! {
+            #dto_field: self.#name,
+        },
+        FieldKind::BoolValue => quote! {
+            #dto_field: self.#name.get_value(),
+        },
+    }
+}
+
+fn generate_register_field(field: &FormField) -> Option<TokenStream> {
+    let name = field.name;
+    let field_name = field.get_field_name();
+
+    match field.kind {
+        FieldKind::Bool => None,
+        _ => Some(quote! {
+            .register(#field_name, &self.#name)
+        }),
+    }
+}
+
+fn generate_register_field_mut(field: &FormField) -> Option<TokenStream> {
+    let name = field.name;
+    let field_name = field.get_field_name();
+
+    match field.kind {
+        FieldKind::Bool => None,
+        _ => Some(quote! {
+            .register(#field_name, &mut self.#name)
+        }),
+    }
+}
+
+fn generate_render_field(index: usize, field: &FormField) -> TokenStream {
+    let name = field.name;
+    let element_name = format_ident!("field_{}", index);
+    let caption = field.caption.as_str();
+
+    let element = match field.kind {
+        FieldKind::InputValue => quote! {
+            ::dioxus_admin_ui_kit::components::InputValueComponent::new(#caption)
+                .set_detailed_value(&value.#name)
+                .set_required(true)
+                .on_input(move |new_value: String| {
+                    form.write().#name.set_string_value(new_value);
+                })
+                .render()
+        },
+        FieldKind::InputValueOpt => quote! {
+            ::dioxus_admin_ui_kit::components::InputValueComponent::new(#caption)
+                .set_detailed_value(&value.#name)
+                .on_input(move |new_value: String| {
+                    form.write().#name.set_value(new_value);
+                })
+                .render()
+        },
+        FieldKind::SelectEnumValueOpt => quote! {
+            ::dioxus_admin_ui_kit::components::select_enum_value_opt(
+                #caption,
+                &value.#name,
+                EventHandler::new(move |new_value| {
+                    form.write().#name.set_value(new_value);
+                }),
+            )
+        },
+        FieldKind::Bool => quote! {
+            ::dioxus_admin_ui_kit::components::input_bool(
+                #caption,
+                value.#name,
+                EventHandler::new(move |new_value: bool| {
+                    form.write().#name = new_value;
+                }),
+            )
+        },
+        FieldKind::BoolValue => quote! {
+            ::dioxus_admin_ui_kit::components::input_bool(
+                #caption,
+                value.#name.get_value(),
+                EventHandler::new(move |new_value: bool| {
+                    form.write().#name.set_value(new_value);
+                }),
+            )
+        },
+    };
+
+    quote! {
+        let #element_name = {
+            let mut form = form;
+            #element
+        };
+    }
+}
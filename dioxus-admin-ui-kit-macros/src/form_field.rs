@@ -0,0 +1,89 @@
+use syn::{Field, Ident, LitStr, Type};
+
+pub enum FieldKind {
+    InputValue,
+    InputValueOpt,
+    SelectEnumValueOpt,
+    Bool,
+    BoolValue,
+}
+
+impl FieldKind {
+    fn from_type(ty: &Type) -> Option<Self> {
+        let Type::Path(type_path) = ty else {
+            return None;
+        };
+
+        let segment = type_path.path.segments.last()?;
+
+        match segment.ident.to_string().as_str() {
+            "InputValue" => Some(Self::InputValue),
+            "InputValueOpt" => Some(Self::InputValueOpt),
+            "SelectEnumValueOpt" => Some(Self::SelectEnumValueOpt),
+            "bool" => Some(Self::Bool),
+            "BoolValue" => Some(Self::BoolValue),
+            _ => None,
+        }
+    }
+}
+
+pub struct FormField<'s> {
+    pub name: &'s Ident,
+    pub kind: FieldKind,
+    pub caption: String,
+    pub dto_field: Ident,
+}
+
+impl<'s> FormField<'s> {
+    pub fn parse(field: &'s Field) -> syn::Result<Self> {
+        let Some(name) = field.ident.as_ref() else {
+            return Err(syn::Error::new_spanned(
+                field,
+                "AdminForm supports only structs with named fields",
+            ));
+        };
+
+        let Some(kind) = FieldKind::from_type(&field.ty) else {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "AdminForm field must be InputValue<T>, InputValueOpt<T>, SelectEnumValueOpt<E>, bool or BoolValue",
+            ));
+        };
+
+        let mut caption = None;
+        let mut dto_field = None;
+
+        for attr in field.attrs.iter() {
+            if !attr.path().is_ident("admin_form") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("caption") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    caption = Some(value.value());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("dto_field") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    dto_field = Some(Ident::new(value.value().as_str(), value.span()));
+                    return Ok(());
+                }
+
+                Err(meta.error("expected `caption` or `dto_field`"))
+            })?;
+        }
+
+        Ok(Self {
+            name,
+            kind,
+            caption: caption.unwrap_or_else(|| name.to_string()),
+            dto_field: dto_field.unwrap_or_else(|| name.clone()),
+        })
+    }
+
+    pub fn get_field_name(&self) -> String {
+        self.name.to_string()
+    }
+}
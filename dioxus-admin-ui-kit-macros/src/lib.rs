@@ -0,0 +1,16 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+mod admin_form;
+mod form_field;
+
+#[proc_macro_derive(AdminForm, attributes(admin_form))]
+pub fn admin_form(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match admin_form::generate(&ast) {
+        Ok(result) => result.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
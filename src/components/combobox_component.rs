@@ -0,0 +1,549 @@
+use super::{ControlIds, render_required_mark};
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use crate::types::{SelectItemsGroup, SelectValue, SelectedValueOpt};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+const DEFAULT_MAX_RENDERED_OPTIONS: usize = 50;
+
+/// Searchable select of a [`SelectedValueOpt`]. Options are filtered by
+/// [`SelectValue::get_value`] while typing; at most `max_rendered_options` of them
+/// are rendered, so lists of thousands of items stay responsive.
+pub struct ComboboxComponent<'s, TValue: 'static + SelectValue + Clone> {
+    caption: &'s str,
+    value: Option<&'s SelectedValueOpt<TValue>>,
+    read_only: bool,
+    id: Option<&'s str>,
+    placeholder: Option<&'s str>,
+    max_rendered_options: usize,
+    on_select: Option<EventHandler<Option<String>>>,
+}
+
+impl<'s, TValue: 'static + SelectValue + Clone> ComboboxComponent<'s, TValue> {
+    pub fn new(caption: &'s str) -> Self {
+        Self {
+            caption,
+            value: None,
+            read_only: false,
+            id: None,
+            placeholder: None,
+            max_rendered_options: DEFAULT_MAX_RENDERED_OPTIONS,
+            on_select: None,
+        }
+    }
+
+    pub fn set_value(mut self, value: &'s SelectedValueOpt<TValue>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn set_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn set_id(mut self, id: &'s str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn set_placeholder(mut self, placeholder: &'s str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn set_max_rendered_options(mut self, max_rendered_options: usize) -> Self {
+        self.max_rendered_options = max_rendered_options;
+        self
+    }
+
+    /// Emits the id of the picked item, `None` when the selection is cleared.
+    /// Pass it to [`SelectedValueOpt::set_selected`].
+    pub fn on_select(mut self, on_select: impl FnMut(Option<String>) + 'static) -> Self {
+        self.on_select = Some(EventHandler::new(on_select));
+        self
+    }
+
+    pub fn render(self) -> Element {
+        let ids = ControlIds::new(self.caption, self.id);
+
+        let items = match self.value {
+            Some(value) => ComboboxItems(value.get_shared_items()),
+            None => ComboboxItems(Rc::new(Vec::<SelectItemsGroup<TValue>>::new())),
+        };
+        let selected_id = self
+            .value
+            .and_then(|value| value.get_selected())
+            .map(|selected| selected.get_id().to_string());
+
+        let read_only = self.read_only || self.on_select.is_none();
+        let required = self.value.map(|value| value.is_required()).unwrap_or(false);
+
+        rsx! {
+            div { class: Theme::current().wrapper,
+                label { r#for: ids.control.as_str(),
+                    {self.caption}
+                    {render_required_mark(required)}
+                }
+                Combobox {
+                    id: ids.control.clone(),
+                    items,
+                    selected_id,
+                    placeholder: self.placeholder.map(|placeholder| placeholder.to_string()),
+                    read_only,
+                    required,
+                    max_rendered_options: self.max_rendered_options,
+                    on_select: self.on_select.unwrap_or_default(),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComboboxOption {
+    id: String,
+    value: String,
+    /// Lowercased `value`, so filtering does not lowercase every option on each keystroke.
+    search_value: String,
+    group: &'static str,
+}
+
+impl ComboboxOption {
+    fn new(id: &str, value: &str, group: &'static str) -> Self {
+        Self {
+            id: id.to_string(),
+            value: value.to_string(),
+            search_value: value.to_lowercase(),
+            group,
+        }
+    }
+}
+
+trait ComboboxSource {
+    fn get_options(&self) -> Vec<ComboboxOption>;
+}
+
+impl<TValue: SelectValue> ComboboxSource for Vec<SelectItemsGroup<TValue>> {
+    fn get_options(&self) -> Vec<ComboboxOption> {
+        self.iter()
+            .flat_map(|group| {
+                group
+                    .values
+                    .iter()
+                    .map(|item| ComboboxOption::new(item.get_id(), item.get_value(), group.name))
+            })
+            .collect()
+    }
+}
+
+/// Items of the value, equal as long as they are not changed, see
+/// [`SelectedValueOpt::get_shared_items`].
+#[derive(Clone)]
+struct ComboboxItems(Rc<dyn ComboboxSource>);
+
+impl PartialEq for ComboboxItems {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Indexes of the options to render and the amount of all matching ones.
+fn filter_options(options: &[ComboboxOption], query: &str, limit: usize) -> (Vec<usize>, usize) {
+    let query = query.trim().to_lowercase();
+
+    let mut result = Vec::new();
+    let mut total = 0;
+
+    for (index, option) in options.iter().enumerate() {
+        if !query.is_empty() && !option.search_value.contains(query.as_str()) {
+            continue;
+        }
+
+        total += 1;
+        if result.len() < limit {
+            result.push(index);
+        }
+    }
+
+    (result, total)
+}
+
+#[component]
+fn Combobox(
+    id: String,
+    items: ComboboxItems,
+    selected_id: Option<String>,
+    placeholder: Option<String>,
+    read_only: bool,
+    required: bool,
+    max_rendered_options: usize,
+    on_select: EventHandler<Option<String>>,
+) -> Element {
+    // `None` while the user is not typing: the input shows the selected value then.
+    let mut query = use_signal(|| None::<String>);
+    let mut opened = use_signal(|| false);
+    let mut active = use_signal(|| 0usize);
+
+    let options = use_memo(use_reactive!(|items| Rc::new(items.0.get_options())))();
+    let selected = selected_id
+        .and_then(|selected_id| options.iter().position(|option| option.id == selected_id));
+
+    let theme = Theme::current();
+    let translator = Translator::current();
+
+    let (visible, total) = filter_options(
+        options.as_slice(),
+        query.read().as_deref().unwrap_or_default(),
+        max_rendered_options,
+    );
+    let visible = Rc::new(visible);
+
+    let selected_value = selected.map(|index| options[index].value.clone());
+    let value = match query.read().as_ref() {
+        Some(query) => query.clone(),
+        None => selected_value.unwrap_or_default(),
+    };
+
+    let listbox_id = format!("{}-listbox", id);
+    let get_option_id = {
+        let listbox_id = listbox_id.clone();
+        move |index: usize| format!("{}-option-{}", listbox_id, index)
+    };
+    let is_opened = opened() && !read_only;
+
+    let active_descendant = match visible.get(active()) {
+        Some(_) if is_opened => Some(get_option_id(active())),
+        _ => None,
+    };
+
+    let select = {
+        let options = options.clone();
+        move |index: Option<usize>| {
+            on_select.call(index.map(|index| options[index].id.clone()));
+            query.set(None);
+            opened.set(false);
+        }
+    };
+
+    let on_key_down = {
+        let visible = visible.clone();
+        let mut select = select.clone();
+        move |e: KeyboardEvent| match e.key() {
+            Key::ArrowDown => {
+                e.prevent_default();
+                if !opened() {
+                    opened.set(true);
+                    active.set(0);
+                } else if active() + 1 < visible.len() {
+                    active += 1;
+                }
+            }
+            Key::ArrowUp => {
+                e.prevent_default();
+                if active() > 0 {
+                    active -= 1;
+                }
+            }
+            Key::Enter => {
+                if opened()
+                    && let Some(index) = visible.get(active())
+                {
+                    e.prevent_default();
+                    select(Some(*index));
+                }
+            }
+            Key::Escape => {
+                query.set(None);
+                opened.set(false);
+            }
+            _ => {}
+        }
+    };
+
+    let list = if is_opened {
+        let mut prev_group = None;
+        let mut items = Vec::with_capacity(visible.len() + 1);
+
+        for (position, index) in visible.iter().copied().enumerate() {
+            let option = &options[index];
+
+            if prev_group != Some(option.group) {
+                prev_group = Some(option.group);
+                if !option.group.is_empty() {
+                    items.push(rsx! {
+                        li { class: theme.combobox_group, role: "presentation", {option.group} }
+                    });
+                }
+            }
+
+            let class = if position == active() {
+                theme.combobox_option_active
+            } else {
+                theme.combobox_option
+            };
+
+            let mut select = select.clone();
+
+            items.push(rsx! {
+                li {
+                    class,
+                    id: get_option_id(position),
+                    role: "option",
+                    aria_selected: Some(index) == selected,
+                    onmousedown: move |e| e.prevent_default(),
+                    onclick: move |_| select(Some(index)),
+                    {option.value.as_str()}
+                }
+            });
+        }
+
+        let hint = if total == 0 {
+            Some(translator.translate(&MessageKey::NoMatches))
+        } else if total > visible.len() {
+            Some(translator.translate(&MessageKey::MoreItems(total - visible.len())))
+        } else {
+            None
+        };
+
+        if let Some(hint) = hint {
+            items.push(rsx! {
+                li { class: theme.combobox_hint, role: "presentation", {hint} }
+            });
+        }
+
+        rsx! {
+            ul {
+                class: theme.combobox_list,
+                style: theme.combobox_list_style,
+                id: listbox_id.as_str(),
+                role: "listbox",
+                {items.into_iter()}
+            }
+        }
+    } else {
+        rsx! {}
+    };
+
+    // A required value can be replaced with another item, but not cleared.
+    let clear_button = if selected.is_some() && !read_only && !required {
+        let mut select = select.clone();
+        rsx! {
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                aria_label: translator.translate(&MessageKey::Clear),
+                onclick: move |_| select(None),
+                "×"
+            }
+        }
+    } else {
+        rsx! {}
+    };
+
+    rsx! {
+        div { class: theme.combobox, style: theme.combobox_style,
+            div { class: theme.input_group,
+                input {
+                    class: theme.input,
+                    r#type: "text",
+                    id: id.as_str(),
+                    role: "combobox",
+                    autocomplete: "off",
+                    aria_autocomplete: "list",
+                    aria_expanded: is_opened,
+                    aria_controls: listbox_id.as_str(),
+                    aria_activedescendant: active_descendant,
+                    aria_required: required,
+                    placeholder,
+                    readonly: read_only,
+                    value,
+                    oninput: move |e| {
+                        query.set(Some(e.value()));
+                        opened.set(true);
+                        active.set(0);
+                    },
+                    onfocus: move |_| opened.set(true),
+                    onblur: move |_| {
+                        query.set(None);
+                        opened.set(false);
+                    },
+                    onkeydown: on_key_down,
+                }
+                {clear_button}
+            }
+            {list}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+    use crate::test_utils::*;
+    use crate::types::SelectItemsGroup;
+
+    fn create_traders(prefix: &str, amount: usize) -> Vec<Account> {
+        (0..amount)
+            .map(|index| {
+                Account::new(&format!("{}-{}", prefix, index))
+                    .with_name(&format!("{} trader {}", prefix, index))
+            })
+            .collect()
+    }
+
+    fn app() -> Element {
+        let mut value = use_signal(|| {
+            SelectedValueOpt::new(vec![
+                SelectItemsGroup::new("Retail", create_traders("retail", 3000)),
+                SelectItemsGroup::new("Institutional", create_traders("inst", 3)),
+            ])
+        });
+
+        let combobox = ComboboxComponent::new("Trader")
+            .set_value(&*value.read())
+            .on_select(move |id| value.write().set_selected(id.as_deref()))
+            .render();
+
+        let selected = match value.read().get_selected() {
+            Some(selected) => selected.get_id().to_string(),
+            None => "none".to_string(),
+        };
+        render_with_value(combobox, selected)
+    }
+
+    #[test]
+    fn caps_rendered_options() {
+        let options: Vec<_> = create_traders("retail", 3000)
+            .into_iter()
+            .map(|account| ComboboxOption::new(&account.id, &account.name, ""))
+            .collect();
+
+        let (visible, total) = filter_options(&options, "TRADER 29", 5);
+        assert_eq!(total, 111);
+        assert_eq!(visible, vec![29, 290, 291, 292, 293]);
+    }
+
+    #[test]
+    fn filters_and_selects_with_keyboard() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_focus_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("role=\"listbox\""));
+        assert_eq!(html.matches("role=\"option\"").count(), 50);
+        assert!(html.contains("2953 more, keep typing to narrow down"));
+
+        harness.fire_input("inst");
+        let html = harness.render_html();
+        assert!(html.contains(">Institutional</li>"));
+        assert_eq!(html.matches("role=\"option\"").count(), 3);
+
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::empty());
+        harness.fire_key_down_at(0, Key::Enter, Modifiers::empty());
+        let html = harness.render_html();
+        assert!(html.contains("value: inst-1"));
+        assert!(html.contains("value=\"inst trader 1\""));
+        assert!(!html.contains("role=\"listbox\""));
+    }
+
+    fn selected_app() -> Element {
+        let mut value = use_signal(|| {
+            let traders = create_traders("inst", 3);
+            let selected = traders[2].clone();
+            SelectedValueOpt::new_with_selected(vec![traders.into()], Some(&selected))
+        });
+
+        let combobox = ComboboxComponent::new("Trader")
+            .set_value(&*value.read())
+            .on_select(move |id| value.write().set_selected(id.as_deref()))
+            .render();
+
+        let selected = match value.read().get_selected() {
+            Some(selected) => selected.get_id().to_string(),
+            None => "none".to_string(),
+        };
+        render_with_value(combobox, selected)
+    }
+
+    #[test]
+    fn shows_and_clears_selected_value() {
+        let mut harness = ComponentHarness::new(selected_app);
+        assert!(harness.render_html().contains("value=\"inst trader 2\""));
+
+        harness.fire_click_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("value: none"));
+        assert!(!html.contains("aria-label=\"Clear\""));
+    }
+
+    fn growing_app() -> Element {
+        let mut value =
+            use_signal(|| SelectedValueOpt::new(vec![create_traders("inst", 2).into()]));
+
+        let combobox = ComboboxComponent::new("Trader")
+            .set_value(&*value.read())
+            .on_select(move |id| value.write().set_selected(id.as_deref()))
+            .render();
+
+        rsx! {
+            {combobox}
+            button {
+                onclick: move |_| {
+                    value
+                        .write()
+                        .push_item(Account::new("new").with_name("New trader"))
+                },
+                "Add"
+            }
+        }
+    }
+
+    #[test]
+    fn rebuilds_options_when_items_change() {
+        let mut harness = ComponentHarness::new(growing_app);
+
+        harness.fire_focus_at(0);
+        assert_eq!(harness.render_html().matches("role=\"option\"").count(), 2);
+
+        harness.fire_click_at(0);
+        harness.fire_input("new");
+        let html = harness.render_html();
+        assert_eq!(html.matches("role=\"option\"").count(), 1);
+        assert!(html.contains(">New trader</li>"));
+    }
+
+    fn required_app() -> Element {
+        let mut value = use_signal(|| {
+            let traders = create_traders("inst", 3);
+            let selected = traders[2].clone();
+            SelectedValueOpt::new_with_selected(vec![traders.into()], Some(&selected))
+                .allow_null_result(false)
+        });
+
+        ComboboxComponent::new("Trader")
+            .set_value(&*value.read())
+            .on_select(move |id| value.write().set_selected(id.as_deref()))
+            .render()
+    }
+
+    #[test]
+    fn does_not_offer_clearing_required_value() {
+        let harness = ComponentHarness::new(required_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("aria-required=\"true\""));
+        assert!(html.contains("edit-required-mark"));
+        assert!(!html.contains("aria-label=\"Clear\""));
+    }
+
+    #[test]
+    fn reports_no_matches() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_input("nobody");
+        assert!(harness.render_html().contains("No matches"));
+    }
+}
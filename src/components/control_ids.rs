@@ -0,0 +1,75 @@
+/// Ids tying a control to its label, validation message and help text.
+///
+/// Derived from the caption, so the server and the hydrated client render the same ids.
+/// Controls sharing a caption on one page need an explicit id.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ControlIds {
+    pub control: String,
+}
+
+impl ControlIds {
+    pub fn new(caption: &str, id: Option<&str>) -> Self {
+        let control = match id {
+            Some(id) => id.to_string(),
+            None => format!("edit-{}", to_slug(caption)),
+        };
+
+        Self { control }
+    }
+
+    pub fn get_error_id(&self) -> String {
+        format!("{}-error", self.control)
+    }
+
+    pub fn get_help_id(&self) -> String {
+        format!("{}-help", self.control)
+    }
+
+    /// Value of `aria-describedby`: the validation message first, then the help text.
+    pub fn get_described_by(&self, has_error: bool, has_help: bool) -> Option<String> {
+        match (has_error, has_help) {
+            (true, true) => Some(format!("{} {}", self.get_error_id(), self.get_help_id())),
+            (true, false) => Some(self.get_error_id()),
+            (false, true) => Some(self.get_help_id()),
+            (false, false) => None,
+        }
+    }
+}
+
+fn to_slug(caption: &str) -> String {
+    let mut result = String::with_capacity(caption.len());
+
+    for c in caption.chars() {
+        if c.is_alphanumeric() {
+            result.extend(c.to_lowercase());
+        } else if !result.is_empty() && !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+
+    while result.ends_with('-') {
+        result.pop();
+    }
+
+    if result.is_empty() {
+        result.push_str("control");
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_id_from_caption() {
+        assert_eq!(
+            ControlIds::new("Time offset", None).control,
+            "edit-time-offset"
+        );
+        assert_eq!(ControlIds::new(" Fee, % ", None).control, "edit-fee");
+        assert_eq!(ControlIds::new("", None).control, "edit-control");
+        assert_eq!(ControlIds::new("Fee", Some("fee")).control, "fee");
+    }
+}
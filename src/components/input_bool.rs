@@ -1,13 +1,30 @@
+use super::ControlIds;
+use crate::theme::Theme;
 use dioxus::prelude::*;
 
 pub fn input_bool(caption: &str, value: bool, on_change: EventHandler<bool>) -> Element {
+    let ids = ControlIds::new(caption, None);
+    input_bool_with_id(caption, &ids.control, value, on_change)
+}
+
+/// [`input_bool`] with an explicit id, for checkboxes sharing a caption on one page.
+pub fn input_bool_with_id(
+    caption: &str,
+    id: &str,
+    value: bool,
+    on_change: EventHandler<bool>,
+) -> Element {
+    let ids = ControlIds::new(caption, Some(id));
+    let theme = Theme::current();
+
     rsx! {
-        div { class: "edit-wrapper",
-            label { {caption} }
+        div { class: theme.wrapper,
+            label { r#for: ids.control.as_str(), {caption} }
 
             input {
-                class: "form-check-input",
-                style: " box-shadow: 0 0 1px gray;  margin-left: 5px; cursor: pointer;",
+                class: theme.checkbox,
+                id: ids.control.as_str(),
+                style: theme.checkbox_style,
                 r#type: "checkbox",
                 checked: value,
 
@@ -19,3 +36,47 @@ pub fn input_bool(caption: &str, value: bool, on_change: EventHandler<bool>) ->
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| false);
+        let checkbox = input_bool(
+            "Enabled",
+            *value.read(),
+            EventHandler::new(move |checked| value.set(checked)),
+        );
+
+        rsx! {
+            {checkbox}
+            div { "value: {value}" }
+        }
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("input_bool", &harness.render_html());
+    }
+
+    #[test]
+    fn emits_checked_value() {
+        let mut harness = ComponentHarness::new(app);
+        harness.fire_checked(true);
+        assert!(harness.render_html().contains("value: true"));
+    }
+
+    fn app_with_id() -> Element {
+        input_bool_with_id("Enabled", "live-enabled", true, EventHandler::new(|_| {}))
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("for=\"live-enabled\""));
+        assert!(html.contains("id=\"live-enabled\""));
+    }
+}
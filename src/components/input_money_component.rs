@@ -0,0 +1,225 @@
+use super::{ControlIds, get_validation_state, render_required_mark};
+use crate::theme::Theme;
+use crate::types::{
+    DetailedValidator, InputFormatter, InputMoneyValue, ValidationError, ValidationMessageFormatter,
+};
+use dioxus::prelude::*;
+use rust_extensions::AsStr;
+use std::rc::Rc;
+
+const RESTORE_VALUE_SCRIPT: &str = r#"const [id, value] = await dioxus.recv();
+const el = document.getElementById(id);
+if (el) {
+    el.value = value;
+}"#;
+
+/// Money input with the currency symbol in front. Input with more decimal places
+/// than the scale of the value is rejected while typing.
+pub struct InputMoneyComponent<'s> {
+    caption: &'s str,
+    value: &'s InputMoneyValue,
+    read_only: bool,
+    required: bool,
+    id: Option<&'s str>,
+    on_input: Option<EventHandler<String>>,
+    on_press_enter: Option<EventHandler<()>>,
+    message_formatter: Option<ValidationMessageFormatter>,
+}
+
+impl<'s> InputMoneyComponent<'s> {
+    pub fn new(caption: &'s str, value: &'s InputMoneyValue) -> Self {
+        Self {
+            caption,
+            value,
+            read_only: false,
+            required: false,
+            id: None,
+            on_input: None,
+            on_press_enter: None,
+            message_formatter: None,
+        }
+    }
+
+    /// Shows an asterisk after the caption and reports a blank value once it is touched.
+    pub fn set_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn set_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn set_id(mut self, id: &'s str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn on_input(mut self, on_input: impl FnMut(String) + 'static) -> Self {
+        self.on_input = Some(EventHandler::new(on_input));
+        self
+    }
+
+    pub fn on_press_enter(mut self, on_input: impl Into<EventHandler<()>>) -> Self {
+        self.on_press_enter = Some(on_input.into());
+        self
+    }
+
+    pub fn set_message_formatter(
+        mut self,
+        formatter: impl Fn(&ValidationError) -> Option<String> + 'static,
+    ) -> Self {
+        self.message_formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    pub fn render(self) -> Element {
+        let theme = Theme::current();
+
+        let mut style = if self.read_only {
+            theme.input_read_only_style
+        } else {
+            ""
+        };
+
+        let required = self.required || self.value.is_required();
+
+        let ids = ControlIds::new(self.caption, self.id);
+        let validation = get_validation_state(
+            Some(self.value),
+            self.required,
+            self.message_formatter.as_ref(),
+            &ids,
+        );
+        if validation.aria_invalid {
+            style = theme.input_error_style;
+        }
+
+        let on_enter_pressed = self.on_press_enter;
+        let on_input = self.on_input;
+        let value_as_str = self.value.as_str();
+        let prev_value = self.value.as_str().to_string();
+        let formatted_value = self.value.format_on_blur();
+        let money_value = self.value.clone();
+        let control_id = ids.control.clone();
+
+        rsx! {
+            div { class: "{theme.wrapper} {validation.error_class}",
+                label { r#for: ids.control.as_str(),
+                    {self.caption}
+                    {render_required_mark(required)}
+                }
+                {validation.message}
+
+                div { class: theme.input_group,
+                    span {
+                        class: theme.input_group_text,
+                        title: self.value.get_currency(),
+                        {self.value.get_currency_symbol()}
+                    }
+                    input {
+                        class: theme.input,
+                        r#type: "text",
+                        id: ids.control.as_str(),
+                        inputmode: "decimal",
+                        style: "{style}",
+                        value: value_as_str,
+                        readonly: self.read_only,
+                        aria_required: required,
+                        aria_invalid: validation.aria_invalid,
+                        aria_describedby: validation.aria_describedby,
+                        oninput: move |e| {
+                            let Some(on_input) = on_input else {
+                                return;
+                            };
+
+                            let value = e.value();
+
+                            if money_value.accepts_input(value.as_str()) {
+                                on_input.call(value);
+                            } else {
+                                // The value has not changed, so there is nothing to re-render:
+                                // put the previous value back into the input manually.
+                                restore_value(control_id.clone(), prev_value.clone());
+                            }
+                        },
+                        onblur: move |_| {
+                            if let Some(on_input) = on_input
+                                && let Some(formatted_value) = formatted_value.clone()
+                            {
+                                on_input.call(formatted_value);
+                            }
+                        },
+                        onkeyup: move |e| {
+                            if e.key() == Key::Enter
+                                && let Some(on_enter_pressed) = on_enter_pressed.as_ref()
+                            {
+                                on_enter_pressed.call(());
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn restore_value(id: String, value: String) {
+    let eval = document::eval(RESTORE_VALUE_SCRIPT);
+    let _ = eval.send((id, value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::types::MoneyAmount;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| {
+            InputMoneyValue::new(MoneyAmount::new(1050, 2).unwrap(), "USD")
+                .set_min_value(MoneyAmount::new(1, 0).unwrap())
+        });
+
+        let input = InputMoneyComponent::new("Price", &value.read())
+            .on_input(move |v| value.write().set_string_value(v))
+            .render();
+
+        rsx! {
+            {input}
+            div { "value: {value.read().as_str()}" }
+        }
+    }
+
+    #[test]
+    fn renders_currency_symbol() {
+        let harness = ComponentHarness::new(app);
+        let html = harness.render_html();
+        assert!(html.contains("input-group-text"));
+        assert!(html.contains("$"));
+        assert!(html.contains("value: 10.50"));
+    }
+
+    #[test]
+    fn marks_required_input() {
+        let html = ComponentHarness::new(app).render_html();
+        assert!(html.contains("<label for=\"edit-price\""));
+        assert!(html.contains("edit-required-mark"));
+        assert!(html.contains("aria-required=\"true\""));
+    }
+
+    #[test]
+    fn rejects_over_precision_input() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_input("10.555");
+        assert!(harness.render_html().contains("value: 10.50"));
+
+        harness.fire_input("0.5");
+        let html = harness.render_html();
+        assert!(html.contains("value: 0.5"));
+        assert!(html.contains("must be ≥ 1"));
+        assert!(html.contains("aria-invalid=\"true\" aria-describedby=\"edit-price-error\""));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use dioxus::prelude::*;
+
+/// Leading or trailing part of the input, e.g. `USD`, `%` or an icon.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputAdornment {
+    Text(String),
+    Element(Element),
+}
+
+impl From<&str> for InputAdornment {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for InputAdornment {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Element> for InputAdornment {
+    fn from(element: Element) -> Self {
+        Self::Element(element)
+    }
+}
+
+impl InputAdornment {
+    pub(crate) fn render(&self) -> Element {
+        let class = Theme::current().input_group_text;
+
+        match self {
+            Self::Text(text) => rsx! {
+                span { class, {text.as_str()} }
+            },
+            Self::Element(element) => rsx! {
+                span { class, {element.clone()} }
+            },
+        }
+    }
+}
+
+/// What is rendered around the input inside its `input-group`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct InputAdornments {
+    pub prefix: Option<InputAdornment>,
+    pub suffix: Option<InputAdornment>,
+    pub clearable: bool,
+}
+
+impl InputAdornments {
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_none() && self.suffix.is_none() && !self.clearable
+    }
+
+    pub fn render_prefix(&self) -> Element {
+        match self.prefix.as_ref() {
+            Some(prefix) => prefix.render(),
+            None => rsx! {},
+        }
+    }
+
+    pub fn render_suffix(&self) -> Element {
+        match self.suffix.as_ref() {
+            Some(suffix) => suffix.render(),
+            None => rsx! {},
+        }
+    }
+
+    /// The clear button is hidden while there is nothing to clear.
+    pub fn render_clear_button(
+        &self,
+        visible: bool,
+        mut on_clear: impl FnMut(()) + 'static,
+    ) -> Element {
+        if !self.clearable || !visible {
+            return rsx! {};
+        }
+
+        let aria_label = Translator::current().translate(&MessageKey::Clear);
+
+        rsx! {
+            button {
+                class: Theme::current().input_button,
+                r#type: "button",
+                aria_label,
+                onclick: move |_| on_clear(()),
+                "×"
+            }
+        }
+    }
+}
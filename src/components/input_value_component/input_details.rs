@@ -0,0 +1,22 @@
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+
+use crate::types::{DetailedValidator, InputFormatter};
+
+/// Validation and formatting details the input renders, see `set_detailed_value`.
+pub(crate) trait InputDetails: DetailedValidator + InputFormatter {}
+
+impl<T: DetailedValidator + InputFormatter> InputDetails for T {}
+
+/// Details of a value which implements only [`ValueValidator`]: the message of
+/// a failed validation, no async checks, masks or formatting.
+pub(crate) struct PlainDetails<'s, TValue: ValueValidator>(pub &'s TValue);
+
+impl<TValue: ValueValidator> ValueValidator for PlainDetails<'_, TValue> {
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        self.0.validate_value()
+    }
+}
+
+impl<TValue: ValueValidator> DetailedValidator for PlainDetails<'_, TValue> {}
+
+impl<TValue: ValueValidator> InputFormatter for PlainDetails<'_, TValue> {}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use dioxus::{core::Task, prelude::*};
+
+use crate::types::Clock;
+
+/// Handlers of `InputValueComponent` passed down to the rendered input.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct InputEvents {
+    pub on_input: Option<EventHandler<String>>,
+    pub on_press_enter: Option<EventHandler<()>>,
+    pub on_focus: Option<EventHandler<()>>,
+    pub on_blur: Option<EventHandler<()>>,
+    pub on_change: Option<EventHandler<String>>,
+    pub on_escape: Option<EventHandler<()>>,
+    pub input_debounce: Option<Duration>,
+}
+
+/// Typing state between commits. `typed` is the last value emitted (or about to be
+/// emitted) through `on_input` since the last `on_change`.
+#[derive(Clone, Copy)]
+pub(crate) struct InputEventsState {
+    typed: Signal<Option<String>>,
+    debounce_task: Signal<Option<Task>>,
+}
+
+pub(crate) fn use_input_events_state() -> InputEventsState {
+    InputEventsState {
+        typed: use_signal(|| None),
+        debounce_task: use_signal(|| None),
+    }
+}
+
+impl InputEvents {
+    pub fn is_editable(&self) -> bool {
+        self.on_input.is_some() || self.on_change.is_some()
+    }
+
+    pub fn input(&self, mut state: InputEventsState, value: String) {
+        state.typed.set(Some(value.clone()));
+
+        let Some(on_input) = self.on_input else {
+            return;
+        };
+
+        let Some(debounce) = self.input_debounce else {
+            on_input.call(value);
+            return;
+        };
+
+        if let Some(task) = state.debounce_task.take() {
+            task.cancel();
+        }
+
+        let clock = Clock::current();
+        let task = spawn(async move {
+            clock.sleep(debounce).await;
+            state.debounce_task.set(None);
+            on_input.call(value);
+        });
+
+        state.debounce_task.set(Some(task));
+    }
+
+    /// Emits the debounced value right away, e.g. because the user has left the input.
+    pub fn flush(&self, mut state: InputEventsState) {
+        let Some(task) = state.debounce_task.take() else {
+            return;
+        };
+
+        task.cancel();
+
+        if let Some(on_input) = self.on_input
+            && let Some(typed) = state.typed.peek().clone()
+        {
+            on_input.call(typed);
+        }
+    }
+
+    /// Flushes the debounced value and emits `on_change` if something was typed since the last commit.
+    pub fn commit(&self, mut state: InputEventsState) {
+        self.flush(state);
+
+        let Some(typed) = state.typed.take() else {
+            return;
+        };
+
+        if let Some(on_change) = self.on_change {
+            on_change.call(typed);
+        }
+    }
+
+    /// Empties the input as if the user has deleted the text and committed it.
+    pub fn clear(&self, state: InputEventsState) {
+        self.input(state, String::new());
+        self.commit(state);
+    }
+
+    pub fn focus(&self) {
+        if let Some(on_focus) = self.on_focus {
+            on_focus.call(());
+        }
+    }
+
+    /// `formatted_value` is what the value turns into after blur, see `InputFormatter`.
+    pub fn blur(&self, state: InputEventsState, formatted_value: Option<String>) {
+        if let Some(formatted_value) = formatted_value {
+            self.input(state, formatted_value);
+        }
+
+        self.commit(state);
+
+        if let Some(on_blur) = self.on_blur {
+            on_blur.call(());
+        }
+    }
+
+    pub fn key_down(&self, key: &Key) {
+        if *key == Key::Escape
+            && let Some(on_escape) = self.on_escape
+        {
+            on_escape.call(());
+        }
+    }
+
+    pub fn key_up(&self, state: InputEventsState, key: &Key) {
+        if *key != Key::Enter {
+            return;
+        }
+
+        self.commit(state);
+
+        if let Some(on_press_enter) = self.on_press_enter {
+            on_press_enter.call(());
+        }
+    }
+}
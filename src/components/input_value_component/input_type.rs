@@ -0,0 +1,41 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputType {
+    #[default]
+    Text,
+    Password,
+    Email,
+    Number,
+    Tel,
+    Url,
+    Search,
+}
+
+impl InputType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Password => "password",
+            Self::Email => "email",
+            Self::Number => "number",
+            Self::Tel => "tel",
+            Self::Url => "url",
+            Self::Search => "search",
+        }
+    }
+}
+
+/// Optional attributes of the rendered `input` element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct InputAttributes {
+    pub input_type: InputType,
+    pub placeholder: Option<String>,
+    pub autofocus: bool,
+    pub autocomplete: Option<String>,
+    pub inputmode: Option<String>,
+    pub max_length: Option<usize>,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub aria_required: bool,
+    pub aria_invalid: bool,
+    pub aria_describedby: Option<String>,
+}
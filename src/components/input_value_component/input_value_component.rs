@@ -0,0 +1,794 @@
+use crate::components::{
+    ControlIds, get_validation_message, render_required_mark, render_validation_message,
+};
+use crate::theme::Theme;
+use crate::types::{
+    DetailedValidator, InputFormatter, InputStepper, ValidationError, ValidationMessageFormatter,
+};
+use dioxus::prelude::*;
+use rust_common::validators::ValueValidator;
+use rust_extensions::AsStr;
+use std::{marker::PhantomData, rc::Rc, time::Duration};
+
+use super::{
+    InputAdornment, InputAdornments, InputAttributes, InputDetails, InputEvents, InputType,
+    NumericInput, NumericSteps, PlainDetails, TextInput,
+};
+
+pub struct InputValueComponent<'s, TValue: 'static + AsStr + ValueValidator> {
+    caption: &'s str,
+    value: Option<&'s TValue>,
+    details: Option<&'s dyn InputDetails>,
+    read_only: bool,
+    required: bool,
+    events: InputEvents,
+    message_formatter: Option<ValidationMessageFormatter>,
+    steps: Option<NumericSteps>,
+    attributes: InputAttributes,
+    adornments: InputAdornments,
+    help_text: Option<&'s str>,
+    ph: PhantomData<TValue>,
+}
+
+impl<'s, TValue: 'static + AsStr + ValueValidator> InputValueComponent<'s, TValue> {
+    pub fn new(caption: &'s str) -> Self {
+        Self {
+            caption,
+            value: None,
+            details: None,
+            read_only: false,
+            required: false,
+            events: InputEvents::default(),
+            message_formatter: None,
+            steps: None,
+            attributes: InputAttributes::default(),
+            adornments: InputAdornments::default(),
+            help_text: None,
+            ph: Default::default(),
+        }
+    }
+
+    /// Shows the message of a failed validation. Use `set_detailed_value` for values
+    /// which implement `DetailedValidator` and `InputFormatter`, e.g. `InputValue`.
+    pub fn set_value(mut self, value: &'s TValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Shows an asterisk after the caption and reports a blank value once it is touched,
+    /// also for a value which is optional itself, e.g. `InputValueOpt`.
+    pub fn set_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn set_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// [`InputType::Password`] gets a show/hide toggle button.
+    pub fn set_input_type(mut self, input_type: InputType) -> Self {
+        self.attributes.input_type = input_type;
+        self
+    }
+
+    pub fn set_placeholder(mut self, placeholder: &str) -> Self {
+        self.attributes.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    pub fn set_autofocus(mut self, autofocus: bool) -> Self {
+        self.attributes.autofocus = autofocus;
+        self
+    }
+
+    /// Value of the `autocomplete` attribute, e.g. `off`, `username`, `new-password`.
+    pub fn set_autocomplete(mut self, autocomplete: &str) -> Self {
+        self.attributes.autocomplete = Some(autocomplete.to_string());
+        self
+    }
+
+    /// Value of the `inputmode` attribute, e.g. `numeric`, `decimal`, `email`.
+    pub fn set_inputmode(mut self, inputmode: &str) -> Self {
+        self.attributes.inputmode = Some(inputmode.to_string());
+        self
+    }
+
+    pub fn set_max_length(mut self, max_length: usize) -> Self {
+        self.attributes.max_length = Some(max_length);
+        self
+    }
+
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.attributes.name = Some(name.to_string());
+        self
+    }
+
+    /// By default the id is derived from the caption. Set it when two inputs
+    /// on one page share a caption.
+    pub fn set_id(mut self, id: &str) -> Self {
+        self.attributes.id = Some(id.to_string());
+        self
+    }
+
+    /// Text like `USD` or an icon `Element` in front of the input.
+    pub fn set_prefix(mut self, prefix: impl Into<InputAdornment>) -> Self {
+        self.adornments.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Text like `%` or an icon `Element` after the input.
+    pub fn set_suffix(mut self, suffix: impl Into<InputAdornment>) -> Self {
+        self.adornments.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Shows the × button which empties the value.
+    pub fn set_clearable(mut self, clearable: bool) -> Self {
+        self.adornments.clearable = clearable;
+        self
+    }
+
+    /// Hint under the input. Unlike the validation message it is always shown.
+    pub fn set_help_text(mut self, help_text: &'s str) -> Self {
+        self.help_text = Some(help_text);
+        self
+    }
+
+    pub fn on_input(mut self, on_input: impl FnMut(String) + 'static) -> Self {
+        self.events.on_input = Some(EventHandler::new(on_input));
+        self
+    }
+
+    /// Delays `on_input` until the user stops typing for `debounce`.
+    /// Blur and Enter emit the pending value right away.
+    pub fn set_input_debounce(mut self, debounce: Duration) -> Self {
+        self.events.input_debounce = Some(debounce);
+        self
+    }
+
+    pub fn on_press_enter(mut self, on_input: impl Into<EventHandler<()>>) -> Self {
+        self.events.on_press_enter = Some(on_input.into());
+        self
+    }
+
+    pub fn on_focus(mut self, on_focus: impl FnMut(()) + 'static) -> Self {
+        self.events.on_focus = Some(EventHandler::new(on_focus));
+        self
+    }
+
+    pub fn on_blur(mut self, on_blur: impl FnMut(()) + 'static) -> Self {
+        self.events.on_blur = Some(EventHandler::new(on_blur));
+        self
+    }
+
+    /// Commits the typed value on blur or Enter, if anything was typed since the last commit.
+    pub fn on_change(mut self, on_change: impl FnMut(String) + 'static) -> Self {
+        self.events.on_change = Some(EventHandler::new(on_change));
+        self
+    }
+
+    pub fn on_escape(mut self, on_escape: impl FnMut(()) + 'static) -> Self {
+        self.events.on_escape = Some(EventHandler::new(on_escape));
+        self
+    }
+
+    pub fn set_message_formatter(
+        mut self,
+        formatter: impl Fn(&ValidationError) -> Option<String> + 'static,
+    ) -> Self {
+        self.message_formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    pub fn render(self) -> Element {
+        let theme = Theme::current();
+
+        let mut style = if self.read_only {
+            theme.input_read_only_style
+        } else {
+            ""
+        };
+
+        let plain_details = self.value.map(PlainDetails);
+        let details = match self.details {
+            Some(details) => Some(details),
+            None => plain_details
+                .as_ref()
+                .map(|details| details as &dyn InputDetails),
+        };
+
+        let validation_pending = match details {
+            Some(details) => details.is_validation_pending(),
+            None => false,
+        };
+        let formatted_value = details.and_then(|details| details.format_on_blur());
+        let validation_message = details.and_then(|details| {
+            get_validation_message(details, self.required, self.message_formatter.as_ref())
+        });
+
+        let required = self.required || details.is_some_and(|details| details.is_required());
+
+        let mut validation_error_class = "";
+        if validation_message.is_some() {
+            style = theme.input_error_style;
+            validation_error_class = theme.wrapper_error;
+        }
+
+        let ids = ControlIds::new(self.caption, self.attributes.id.as_deref());
+        let mut attributes = self.attributes;
+        attributes.id = Some(ids.control.clone());
+        attributes.aria_required = required;
+
+        let value_as_str = self.value.map(|value| value.as_str()).unwrap_or_default();
+        let mask = details
+            .and_then(|details| details.get_input_mask())
+            .cloned();
+        let value_as_str = match mask.as_ref() {
+            Some(mask) => mask.apply(value_as_str),
+            None => value_as_str.to_string(),
+        };
+
+        let has_validation_message = validation_message.is_some();
+        let validation_message =
+            render_validation_message(validation_message.as_deref(), &ids.get_error_id());
+
+        let pending_spinner = if validation_pending {
+            rsx! {
+                span {
+                    class: theme.spinner,
+                    role: "status",
+                    style: theme.spinner_style,
+                    aria_hidden: "true",
+                }
+            }
+        } else {
+            rsx! {}
+        };
+
+        let has_help_text = self.help_text.is_some();
+        let help_text = match self.help_text {
+            Some(help_text) => rsx! {
+                div { class: theme.help_text, id: ids.get_help_id(), {help_text} }
+            },
+            None => rsx! {},
+        };
+
+        let events = self.events;
+
+        attributes.aria_invalid = has_validation_message;
+        attributes.aria_describedby = ids.get_described_by(has_validation_message, has_help_text);
+
+        if events.is_editable() {
+            let input = match self.steps {
+                Some(steps) => rsx! {
+                    NumericInput {
+                        value: value_as_str,
+                        attributes: attributes.clone(),
+                        style: style.to_string(),
+                        read_only: self.read_only,
+                        aria_busy: validation_pending,
+                        steps,
+                        formatted_value,
+                        adornments: self.adornments,
+                        events,
+                    }
+                },
+                None => rsx! {
+                    TextInput {
+                        value: value_as_str,
+                        attributes: attributes.clone(),
+                        style: style.to_string(),
+                        read_only: self.read_only,
+                        aria_busy: validation_pending,
+                        mask,
+                        formatted_value,
+                        adornments: self.adornments,
+                        events,
+                    }
+                },
+            };
+
+            rsx! {
+                div { class: "{theme.wrapper} {validation_error_class}",
+                    label { r#for: ids.control.as_str(),
+                        {self.caption}
+                        {render_required_mark(required)}
+                        {pending_spinner}
+                    }
+                    {validation_message}
+
+                    {input}
+                    {help_text}
+                }
+            }
+        } else {
+            rsx! {
+                div { class: "{theme.wrapper} {validation_error_class}",
+                    label { r#for: ids.control.as_str(),
+                        {self.caption}
+                        {render_required_mark(required)}
+                    }
+                    {validation_message}
+
+                    TextInput {
+                        value: value_as_str,
+                        attributes: attributes.clone(),
+                        style: style.to_string(),
+                        read_only: self.read_only,
+                        aria_busy: false,
+                        adornments: self.adornments,
+                        events: InputEvents {
+                            on_press_enter: events.on_press_enter,
+                            ..Default::default()
+                        },
+                    }
+                    {help_text}
+                }
+            }
+        }
+    }
+}
+
+impl<'s, TValue: 'static + AsStr + DetailedValidator + InputFormatter>
+    InputValueComponent<'s, TValue>
+{
+    /// Like `set_value`, plus the async check, the mask, formatting on blur and
+    /// the required state of the value, e.g. of `InputValue`.
+    pub fn set_detailed_value(mut self, value: &'s TValue) -> Self {
+        self.details = Some(value);
+        self.value = Some(value);
+        self
+    }
+}
+
+impl<'s, TValue: 'static + AsStr + DetailedValidator + InputFormatter + InputStepper>
+    InputValueComponent<'s, TValue>
+{
+    /// Numeric variant with +/− buttons, ArrowUp/ArrowDown (Shift for ×10 steps)
+    /// and mouse wheel while the input is focused.
+    pub fn set_numeric_value(mut self, value: &'s TValue) -> Self {
+        self.steps = Some(NumericSteps {
+            up: value.get_stepped_string(1),
+            down: value.get_stepped_string(-1),
+            up_10: value.get_stepped_string(10),
+            down_10: value.get_stepped_string(-10),
+        });
+        self.set_detailed_value(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::types::{InputMask, InputValue, InputValueOpt};
+    use rust_common::validators::ValueValidationResult;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| InputValue::new(5).set_min_value_mut(1));
+        let mut entered = use_signal(|| 0);
+
+        let input = InputValueComponent::new("Amount")
+            .set_detailed_value(&*value.read())
+            .on_input(move |v| value.write().set_string_value(v))
+            .on_press_enter(EventHandler::new(move |_| entered += 1))
+            .render();
+
+        rsx! {
+            {input}
+            div { "entered: {entered}" }
+        }
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("input_value_component", &harness.render_html());
+    }
+
+    #[test]
+    fn shows_validation_message_after_input() {
+        let mut harness = ComponentHarness::new(app);
+        harness.fire_input("0");
+
+        let html = harness.render_html();
+        assert!(html.contains("must be ≥ 1"));
+        assert!(html.contains("edit-wrapper-validation-error"));
+    }
+
+    #[test]
+    fn describes_input_with_message_and_help() {
+        let mut harness = ComponentHarness::new(adorned_app);
+        harness.fire_input("-1");
+
+        let html = harness.render_html();
+        assert!(html.contains("<label for=\"edit-fee\""));
+        assert!(html.contains("id=\"edit-fee-error\" role=\"alert\""));
+        assert!(html.contains("aria-invalid=\"true\""));
+        assert!(html.contains("aria-describedby=\"edit-fee-error edit-fee-help\""));
+    }
+
+    #[test]
+    fn calls_on_press_enter() {
+        let mut harness = ComponentHarness::new(app);
+        harness.fire_key_up(Key::Enter);
+        assert!(harness.render_html().contains("entered: 1"));
+    }
+
+    thread_local! {
+        static VALIDATOR: FakeAsyncValidator = FakeAsyncValidator::new()
+            .reject("admin", ValidationError::custom("username is taken"))
+            .hold();
+    }
+
+    fn app_with_async_validator() -> Element {
+        let mut value = use_signal(|| {
+            let validator = VALIDATOR.with(|validator| validator.clone());
+            InputValue::new("guest".to_string())
+                .with_async_validator(crate::types::AsyncValidator::new(validator))
+        });
+
+        InputValueComponent::new("Username")
+            .set_detailed_value(&*value.read())
+            .on_input(move |v| value.write().set_string_value(v))
+            .render()
+    }
+
+    #[test]
+    fn shows_pending_state_until_async_check_is_done() {
+        let mut harness = ComponentHarness::new(app_with_async_validator);
+        harness.fire_input("admin");
+
+        let html = harness.render_html();
+        assert!(html.contains("spinner-border"));
+        assert!(html.contains("aria-busy=\"true\""));
+
+        VALIDATOR.with(|validator| validator.release());
+        harness.run_tasks();
+
+        let html = harness.render_html();
+        assert!(!html.contains("spinner-border"));
+        assert!(html.contains("username is taken"));
+
+        harness.fire_input("root");
+        harness.run_tasks();
+        assert!(
+            !harness
+                .render_html()
+                .contains("edit-validation-err-message")
+        );
+    }
+
+    fn numeric_app() -> Element {
+        let mut value = use_signal(|| {
+            InputValue::new(5)
+                .set_min_value_mut(0)
+                .set_max_value_mut(20)
+                .set_step(2)
+        });
+
+        InputValueComponent::new("Quantity")
+            .set_numeric_value(&*value.read())
+            .on_input(move |v| value.write().set_string_value(v))
+            .render()
+    }
+
+    #[test]
+    fn steps_numeric_value_with_buttons_keys_and_wheel() {
+        let mut harness = ComponentHarness::new(numeric_app);
+
+        harness.fire_click_at(1);
+        assert!(harness.render_html().contains("value=\"7\""));
+
+        harness.fire_key_down_at(0, Key::ArrowUp, Modifiers::SHIFT);
+        assert!(harness.render_html().contains("value=\"20\""));
+
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::empty());
+        assert!(harness.render_html().contains("value=\"18\""));
+
+        harness.fire_wheel_at(0, -1.0);
+        assert!(harness.render_html().contains("value=\"18\""));
+
+        harness.fire_focus_at(0);
+        harness.fire_wheel_at(0, 1.0);
+        assert!(harness.render_html().contains("value=\"16\""));
+
+        harness.fire_click_at(0);
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::SHIFT);
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::SHIFT);
+        assert!(harness.render_html().contains("value=\"0\""));
+    }
+
+    fn masked_app() -> Element {
+        let mut value =
+            use_signal(|| InputValue::new(String::new()).with_mask(InputMask::new("####-####")));
+
+        let input = InputValueComponent::new("Id")
+            .set_detailed_value(&*value.read())
+            .on_input(move |v| value.write().set_string_value(v))
+            .render();
+
+        rsx! {
+            {input}
+            div { "stored: {value.read().as_str()}" }
+        }
+    }
+
+    #[test]
+    fn stores_unmasked_value_and_validates_completeness() {
+        let mut harness = ComponentHarness::new(masked_app);
+        assert!(harness.render_html().contains("placeholder=\"____-____\""));
+
+        harness.fire_input("1234-5x");
+        let html = harness.render_html();
+        assert!(html.contains("stored: 12345"));
+        assert!(html.contains("value=\"1234-5\""));
+        assert!(html.contains("must match ____-____"));
+
+        harness.fire_input("1234-5678");
+        let html = harness.render_html();
+        assert!(html.contains("stored: 12345678"));
+        assert!(!html.contains("edit-validation-err-message"));
+    }
+
+    fn password_app() -> Element {
+        let mut value = use_signal(|| InputValue::new(String::new()));
+
+        InputValueComponent::new("Password")
+            .set_detailed_value(&*value.read())
+            .set_input_type(InputType::Password)
+            .set_placeholder("at least 8 characters")
+            .set_autocomplete("new-password")
+            .set_max_length(64)
+            .set_name("password")
+            .on_input(move |v| value.write().set_string_value(v))
+            .render()
+    }
+
+    #[test]
+    fn renders_attributes_and_toggles_password_visibility() {
+        let mut harness = ComponentHarness::new(password_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("type=\"password\""));
+        assert!(html.contains("placeholder=\"at least 8 characters\""));
+        assert!(html.contains("autocomplete=\"new-password\""));
+        assert!(html.contains("maxlength=\"64\""));
+        assert!(html.contains("name=\"password\""));
+
+        harness.fire_click_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("type=\"text\""));
+        assert!(html.contains("aria-pressed=\"true\""));
+    }
+
+    fn events_app() -> Element {
+        let mut value = use_signal(|| InputValue::new(String::new()));
+        let mut log = use_signal(Vec::<String>::new);
+
+        let input = InputValueComponent::new("Search")
+            .set_detailed_value(&*value.read())
+            .set_input_debounce(Duration::from_millis(300))
+            .on_input(move |v| value.write().set_string_value(v))
+            .on_focus(move |_| log.write().push("focus".to_string()))
+            .on_blur(move |_| log.write().push("blur".to_string()))
+            .on_change(move |v| log.write().push(format!("change:{}", v)))
+            .on_escape(move |_| log.write().push("escape".to_string()))
+            .render();
+
+        rsx! {
+            {input}
+            div { "log: {log.read().join(\",\")}" }
+        }
+    }
+
+    #[test]
+    fn commits_on_enter_and_blur() {
+        let mut harness = ComponentHarness::new(events_app);
+
+        harness.fire_focus_at(0);
+        harness.fire_input("abc");
+        harness.run_tasks();
+        assert!(harness.render_html().contains("log: focus<"));
+
+        harness.fire_key_up(Key::Enter);
+        assert!(harness.render_html().contains("log: focus,change:abc<"));
+
+        harness.fire_key_down_at(0, Key::Escape, Modifiers::empty());
+        harness.fire_blur_at(0);
+        assert!(
+            harness
+                .render_html()
+                .contains("log: focus,change:abc,escape,blur<")
+        );
+
+        harness.fire_input("abcd");
+        harness.fire_blur_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("change:abcd"));
+        assert!(html.contains("value=\"abcd\""));
+    }
+
+    fn debounced_app() -> Element {
+        let mut value = use_signal(|| InputValue::new(String::new()));
+        let mut inputs = use_signal(Vec::<String>::new);
+
+        let input = InputValueComponent::new("Search")
+            .set_detailed_value(&*value.read())
+            .set_input_debounce(Duration::from_millis(300))
+            .on_input(move |v: String| {
+                inputs.write().push(v.clone());
+                value.write().set_string_value(v);
+            })
+            .render();
+
+        rsx! {
+            {input}
+            div { "inputs: {inputs.read().join(\",\")}" }
+        }
+    }
+
+    #[test]
+    fn debounces_input() {
+        let mut harness = ComponentHarness::new(debounced_app);
+
+        harness.fire_input("a");
+        harness.advance_time(Duration::from_millis(200));
+        harness.fire_input("ab");
+        harness.advance_time(Duration::from_millis(200));
+        assert!(harness.render_html().contains("inputs: <"));
+
+        harness.advance_time(Duration::from_millis(100));
+        assert!(harness.render_html().contains("inputs: ab<"));
+    }
+
+    fn adorned_app() -> Element {
+        let mut value = use_signal(|| InputValue::new(12.5).set_min_value_mut(0.0));
+        let mut changed = use_signal(String::new);
+
+        let input = InputValueComponent::new("Fee")
+            .set_detailed_value(&*value.read())
+            .set_prefix("USD")
+            .set_suffix(rsx! {
+                i { class: "bi bi-percent" }
+            })
+            .set_clearable(true)
+            .set_help_text("Charged per transaction")
+            .on_input(move |v| value.write().set_string_value(v))
+            .on_change(move |v| changed.set(v))
+            .render();
+
+        rsx! {
+            {input}
+            div { "stored: [{value.read().as_str()}] changed: [{changed}]" }
+        }
+    }
+
+    #[test]
+    fn renders_adornments_and_clears_value() {
+        let mut harness = ComponentHarness::new(adorned_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("<span class=\"input-group-text\">USD</span>"));
+        assert!(html.contains("bi-percent"));
+        assert!(
+            html.contains("class=\"form-text\" id=\"edit-fee-help\">Charged per transaction</div>")
+        );
+        assert!(html.contains("aria-label=\"Clear\""));
+
+        harness.fire_click_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("stored: [] changed: []"));
+        assert!(!html.contains("aria-label=\"Clear\""));
+        assert!(html.contains("Charged per transaction"));
+    }
+
+    fn required_app() -> Element {
+        let mut value = use_signal(|| InputValue::<String>::from_str(""));
+
+        InputValueComponent::new("Name")
+            .set_detailed_value(&*value.read())
+            .set_required(true)
+            .on_input(move |v| value.write().set_string_value(v))
+            .render()
+    }
+
+    #[test]
+    fn shows_required_message_once_touched() {
+        let mut harness = ComponentHarness::new(required_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("edit-required-mark"));
+        assert!(html.contains("aria-required=\"true\""));
+        assert!(!html.contains("edit-validation-err-message"));
+
+        harness.fire_input("a");
+        harness.fire_input("");
+        let html = harness.render_html();
+        assert!(html.contains("role=\"alert\">required</div>"));
+    }
+
+    fn read_only_app() -> Element {
+        let value = InputValue::new(0).set_min_value_mut(1);
+
+        InputValueComponent::new("Amount")
+            .set_detailed_value(&value)
+            .set_help_text("Set by the desk")
+            .render()
+    }
+
+    #[test]
+    fn describes_invalid_read_only_value() {
+        let html = ComponentHarness::new(read_only_app).render_html();
+        assert!(html.contains("id=\"edit-amount-error\" role=\"alert\""));
+        assert!(html.contains("aria-invalid=\"true\""));
+        assert!(html.contains("aria-describedby=\"edit-amount-error edit-amount-help\""));
+    }
+
+    struct Ticker(String);
+
+    impl AsStr for Ticker {
+        fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl ValueValidator for Ticker {
+        fn validate_value(&self) -> Result<(), ValueValidationResult> {
+            if self.0.chars().any(|c| c.is_lowercase()) {
+                return Err(ValueValidationResult::IllegalChars);
+            }
+
+            Ok(())
+        }
+    }
+
+    fn plain_app() -> Element {
+        let mut value = use_signal(|| Ticker("EURUSD".to_string()));
+
+        InputValueComponent::new("Ticker")
+            .set_value(&*value.read())
+            .on_input(move |v| value.set(Ticker(v)))
+            .render()
+    }
+
+    #[test]
+    fn validates_plain_value() {
+        let mut harness = ComponentHarness::new(plain_app);
+        assert!(harness.render_html().contains("value=\"EURUSD\""));
+
+        harness.fire_input("eurusd");
+        let html = harness.render_html();
+        assert!(html.contains("role=\"alert\">invalid value</div>"));
+        assert!(html.contains("aria-invalid=\"true\""));
+    }
+
+    fn required_opt_app() -> Element {
+        let mut value = use_signal(|| InputValueOpt::<i64>::new(None));
+
+        InputValueComponent::new("Limit")
+            .set_detailed_value(&*value.read())
+            .set_required(true)
+            .on_input(move |v| value.write().set_value(v))
+            .render()
+    }
+
+    #[test]
+    fn reports_blank_optional_value_if_required() {
+        let mut harness = ComponentHarness::new(required_opt_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("aria-required=\"true\""));
+        assert!(!html.contains("role=\"alert\""));
+
+        harness.fire_input("5");
+        assert!(!harness.render_html().contains("role=\"alert\""));
+
+        harness.fire_input("");
+        let html = harness.render_html();
+        assert!(html.contains("role=\"alert\">required</div>"));
+        assert!(html.contains("aria-invalid=\"true\""));
+    }
+}
@@ -0,0 +1,14 @@
+mod input_value_component;
+pub use input_value_component::*;
+mod input_type;
+pub use input_type::*;
+mod text_input;
+use text_input::*;
+mod numeric_input;
+use numeric_input::*;
+mod input_adornment;
+pub use input_adornment::*;
+mod input_events;
+use input_events::*;
+mod input_details;
+use input_details::*;
@@ -0,0 +1,138 @@
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use dioxus::prelude::*;
+
+use super::{InputAdornments, InputAttributes, InputEvents, use_input_events_state};
+
+/// Values the numeric variant switches to, precomputed during render.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NumericSteps {
+    pub up: Option<String>,
+    pub down: Option<String>,
+    pub up_10: Option<String>,
+    pub down_10: Option<String>,
+}
+
+#[component]
+pub(crate) fn NumericInput(
+    value: String,
+    attributes: InputAttributes,
+    style: String,
+    read_only: bool,
+    aria_busy: bool,
+    steps: NumericSteps,
+    formatted_value: Option<String>,
+    adornments: InputAdornments,
+    events: InputEvents,
+) -> Element {
+    let mut focused = use_signal(|| false);
+    let state = use_input_events_state();
+    let translator = Translator::current();
+    let theme = Theme::current();
+
+    let step_to = move |value: &Option<String>| {
+        if read_only {
+            return;
+        }
+
+        if let Some(value) = value {
+            events.input(state, value.clone());
+            events.flush(state);
+        }
+    };
+
+    let has_value = !value.is_empty() && !read_only;
+    let up = steps.up.clone();
+    let down = steps.down.clone();
+    let wheel_steps = steps.clone();
+    let key_steps = steps.clone();
+    let inputmode = attributes.inputmode.as_deref().unwrap_or("decimal");
+
+    rsx! {
+        div { class: theme.input_group,
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                tabindex: "-1",
+                disabled: read_only || steps.down.is_none(),
+                aria_label: translator.translate(&MessageKey::Decrease),
+                onclick: move |_| step_to(&down),
+                "−"
+            }
+            {adornments.render_prefix()}
+            input {
+                class: theme.input,
+                r#type: "text",
+                inputmode,
+                role: "spinbutton",
+                style: "{style}",
+                value,
+                id: attributes.id.clone(),
+                name: attributes.name.clone(),
+                placeholder: attributes.placeholder.clone(),
+                autocomplete: attributes.autocomplete.clone(),
+                autofocus: attributes.autofocus,
+                maxlength: attributes.max_length,
+                readonly: read_only,
+                aria_busy,
+                aria_required: attributes.aria_required,
+                aria_invalid: attributes.aria_invalid,
+                aria_describedby: attributes.aria_describedby.clone(),
+                oninput: move |e| events.input(state, e.value()),
+                onfocus: move |_| {
+                    focused.set(true);
+                    events.focus();
+                },
+                onblur: move |_| {
+                    focused.set(false);
+                    events.blur(state, formatted_value.clone());
+                },
+                onkeydown: move |e| {
+                    let shift = e.modifiers().shift();
+                    let value = match e.key() {
+                        Key::ArrowUp if shift => &key_steps.up_10,
+                        Key::ArrowUp => &key_steps.up,
+                        Key::ArrowDown if shift => &key_steps.down_10,
+                        Key::ArrowDown => &key_steps.down,
+                        key => {
+                            events.key_down(&key);
+                            return;
+                        }
+                    };
+
+                    e.prevent_default();
+                    step_to(value);
+                },
+                onkeyup: move |e| events.key_up(state, &e.key()),
+                onwheel: move |e| {
+                    if !focused() {
+                        return;
+                    }
+
+                    let delta_y = e.delta().strip_units().y;
+                    if delta_y == 0.0 {
+                        return;
+                    }
+
+                    e.prevent_default();
+                    if delta_y < 0.0 {
+                        step_to(&wheel_steps.up);
+                    } else {
+                        step_to(&wheel_steps.down);
+                    }
+                },
+            }
+            {adornments.render_clear_button(has_value, move |_| events.clear(state))}
+            {adornments.render_suffix()}
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                tabindex: "-1",
+                disabled: read_only || steps.up.is_none(),
+                aria_label: translator.translate(&MessageKey::Increase),
+                onclick: move |_| step_to(&up),
+                "+"
+            }
+        }
+    }
+}
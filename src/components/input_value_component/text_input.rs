@@ -0,0 +1,124 @@
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use crate::types::InputMask;
+use dioxus::prelude::*;
+
+use super::{InputAdornments, InputAttributes, InputEvents, InputType, use_input_events_state};
+
+#[component]
+pub(crate) fn TextInput(
+    value: String,
+    attributes: InputAttributes,
+    style: String,
+    read_only: bool,
+    aria_busy: bool,
+    mask: Option<InputMask>,
+    formatted_value: Option<String>,
+    adornments: InputAdornments,
+    events: InputEvents,
+) -> Element {
+    let mut revealed = use_signal(|| false);
+    let state = use_input_events_state();
+    let theme = Theme::current();
+
+    let is_password = attributes.input_type == InputType::Password;
+    let input_type = if is_password && revealed() {
+        InputType::Text.as_str()
+    } else {
+        attributes.input_type.as_str()
+    };
+
+    let has_value = !value.is_empty() && !read_only;
+
+    let placeholder = match attributes.placeholder.clone() {
+        Some(placeholder) => Some(placeholder),
+        None => mask.as_ref().map(|mask| mask.get_placeholder()),
+    };
+
+    let input = rsx! {
+        input {
+            class: theme.input,
+            r#type: input_type,
+            style: "{style}",
+            value,
+            id: attributes.id.clone(),
+            name: attributes.name.clone(),
+            placeholder,
+            autocomplete: attributes.autocomplete.clone(),
+            inputmode: attributes.inputmode.clone(),
+            autofocus: attributes.autofocus,
+            maxlength: attributes.max_length,
+            readonly: read_only,
+            aria_busy,
+            aria_required: attributes.aria_required,
+            aria_invalid: attributes.aria_invalid,
+            aria_describedby: attributes.aria_describedby.clone(),
+            oninput: move |e| {
+                let value = e.value();
+                match mask.as_ref() {
+                    Some(mask) => on_masked_input(mask, value, |value| events.input(state, value)),
+                    None => events.input(state, value),
+                }
+            },
+            onfocus: move |_| events.focus(),
+            onblur: move |_| events.blur(state, formatted_value.clone()),
+            onkeydown: move |e| events.key_down(&e.key()),
+            onkeyup: move |e| events.key_up(state, &e.key()),
+        }
+    };
+
+    if !is_password && adornments.is_empty() {
+        return input;
+    }
+
+    let password_toggle = if is_password {
+        let aria_label = Translator::current().translate(&MessageKey::ShowPassword);
+        rsx! {
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                aria_label,
+                aria_pressed: revealed(),
+                onclick: move |_| revealed.toggle(),
+                if revealed() {
+                    "🙈"
+                } else {
+                    "👁"
+                }
+            }
+        }
+    } else {
+        rsx! {}
+    };
+
+    rsx! {
+        div { class: theme.input_group,
+            {adornments.render_prefix()}
+            {input}
+            {adornments.render_clear_button(has_value, move |_| events.clear(state))}
+            {password_toggle}
+            {adornments.render_suffix()}
+        }
+    }
+}
+
+/// Emits the unmasked value and puts the masked text into the focused input right away,
+/// keeping the caret after the typed character rather than at the end of the text.
+fn on_masked_input(mask: &InputMask, typed: String, on_input: impl FnOnce(String)) {
+    let value = mask.unmask(typed.as_str());
+    let masked = mask.apply(value.as_str());
+    let caret_map = mask.get_caret_map(typed.as_str());
+
+    on_input(value);
+
+    document::eval(&format!(
+        r#"const el = document.activeElement;
+if (el && el.setSelectionRange) {{
+    const map = {:?};
+    const caret = map[Math.min(el.selectionStart ?? map.length - 1, map.length - 1)];
+    el.value = {:?};
+    el.setSelectionRange(caret, caret);
+}}"#,
+        caret_map, masked
+    ));
+}
@@ -1,10 +1,24 @@
 mod input_value_component;
 pub use input_value_component::*;
+mod input_money_component;
+pub use input_money_component::*;
+mod text_area_component;
+pub use text_area_component::*;
 mod select_enum_value_opt_component;
 pub use select_enum_value_opt_component::*;
+mod select_value_opt_component;
+pub use select_value_opt_component::*;
 mod select_enum_value;
 pub use select_enum_value::*;
 mod input_bool;
 pub use input_bool::*;
+mod multi_select;
+pub use multi_select::*;
+mod combobox_component;
+pub use combobox_component::*;
 mod render_table;
 pub use render_table::*;
+mod control_ids;
+use control_ids::*;
+mod validation_message;
+use validation_message::*;
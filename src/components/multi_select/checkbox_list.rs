@@ -0,0 +1,188 @@
+use super::group_options;
+use crate::components::{ControlIds, get_validation_state, render_required_mark};
+use crate::theme::Theme;
+use crate::types::MultiSelectValue;
+use dioxus::prelude::*;
+
+/// One checkbox per item, the items of a named group under its header.
+/// Once the max amount is selected the rest are disabled.
+/// `on_change` gets the ids of the new selection.
+pub fn checkbox_list(
+    caption: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    checkbox_list_with_id(caption, &ids.control, value, on_change)
+}
+
+/// [`checkbox_list`] with an explicit id, for checkbox lists sharing a caption on one page.
+pub fn checkbox_list_with_id(
+    caption: &str,
+    id: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
+    let caption_id = format!("{}-caption", ids.control);
+
+    let selected_ids = value.get_selected_ids();
+    let can_select_more = value.can_select_more();
+
+    let validation = get_validation_state(Some(value), false, None, &ids);
+
+    let options = value.get_options();
+    let mut index = 0;
+    let mut items = Vec::new();
+
+    for (group_index, (group, options)) in group_options(options.iter()).into_iter().enumerate() {
+        let mut checkboxes = Vec::with_capacity(options.len());
+
+        for option in options {
+            let id = format!("{}-{}", ids.control, index);
+            index += 1;
+            let option = option.clone();
+            let selected_ids = selected_ids.clone();
+
+            checkboxes.push(rsx! {
+                div { class: theme.checkbox_list_item,
+                    input {
+                        r#type: "checkbox",
+                        class: theme.checkbox,
+                        id: id.as_str(),
+                        checked: option.selected,
+                        disabled: !option.selected && !can_select_more,
+                        oninput: move |e| {
+                            let mut ids: Vec<String> = selected_ids
+                                .iter()
+                                .filter(|id| *id != &option.id)
+                                .cloned()
+                                .collect();
+
+                            if e.checked() {
+                                ids.push(option.id.clone());
+                            }
+
+                            on_change.call(ids);
+                        },
+                    }
+                    label { class: theme.checkbox_list_label, r#for: id.as_str(), {option.label.as_str()} }
+                }
+            });
+        }
+
+        if group.is_empty() {
+            items.push(rsx! {
+                {checkboxes.into_iter()}
+            });
+            continue;
+        }
+
+        let group_id = format!("{}-group-{}", ids.control, group_index);
+        items.push(rsx! {
+            div { role: "group", aria_labelledby: group_id.as_str(),
+                div { class: theme.option_group_header, id: group_id.as_str(), {group} }
+                {checkboxes.into_iter()}
+            }
+        });
+    }
+
+    rsx! {
+        div { class: "{theme.wrapper} {validation.error_class}",
+            div { id: caption_id.as_str(),
+                {caption}
+                {render_required_mark(value.is_required())}
+            }
+            {validation.message}
+            div {
+                id: ids.control.as_str(),
+                role: "group",
+                aria_labelledby: caption_id.as_str(),
+                aria_required: value.is_required(),
+                aria_invalid: validation.aria_invalid,
+                aria_describedby: validation.aria_describedby,
+                {items.into_iter()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+    use crate::test_utils::*;
+    use crate::types::*;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| {
+            SelectedValues::new(vec![create_accounts(&["L1", "L2", "D1"]).into()])
+                .set_max_selected(2)
+        });
+
+        let list = checkbox_list(
+            "Accounts",
+            &*value.read(),
+            EventHandler::new(move |ids: Vec<String>| value.write().set_selected_ids(&ids)),
+        );
+
+        let selected = value.read().get_selected_ids().join(",");
+        render_with_value(list, selected)
+    }
+
+    #[test]
+    fn disables_unchecked_items_at_max() {
+        let mut harness = ComponentHarness::new(app);
+
+        let html = harness.render_html();
+        assert!(html.contains("role=\"group\" aria-labelledby=\"edit-accounts-caption\""));
+        assert!(html.contains("<label class=\"form-check-label\" for=\"edit-accounts-2\">D1"));
+
+        harness.fire_input_at(2, "true");
+        harness.fire_input_at(0, "true");
+        let html = harness.render_html();
+        assert!(html.contains("value: D1,L1"));
+        assert_eq!(html.matches("disabled=\"true\"").count(), 1);
+
+        harness.fire_input_at(2, "false");
+        let html = harness.render_html();
+        assert!(html.contains("value: L1"));
+        assert!(!html.contains("disabled=\"true\""));
+    }
+
+    fn grouped_app() -> Element {
+        let value = use_signal(|| SelectedValues::new(create_account_groups()));
+
+        checkbox_list("Accounts", &*value.read(), EventHandler::new(|_| {}))
+    }
+
+    #[test]
+    fn renders_group_headers() {
+        let harness = ComponentHarness::new(grouped_app);
+
+        let html = harness.render_html();
+        assert!(html.contains(
+            "role=\"group\" aria-labelledby=\"edit-accounts-group-1\"><div class=\"small fw-semibold text-body-secondary mt-2 mb-1\" id=\"edit-accounts-group-1\">Demo</div>"
+        ));
+        assert!(html.contains("for=\"edit-accounts-2\">D1"));
+    }
+
+    fn app_with_id() -> Element {
+        let value = use_signal(|| SelectedValues::new(vec![create_accounts(&["L1"]).into()]));
+
+        checkbox_list_with_id(
+            "Accounts",
+            "live-accounts",
+            &*value.read(),
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("aria-labelledby=\"live-accounts-caption\""));
+        assert!(html.contains("for=\"live-accounts-0\">L1"));
+    }
+}
@@ -0,0 +1,464 @@
+use std::rc::Rc;
+
+use super::group_options;
+use crate::components::{ControlIds, get_validation_state, render_required_mark};
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use crate::types::{MultiSelectOption, MultiSelectValue};
+use dioxus::prelude::*;
+
+/// Transfer box: available items on the left, selected on the right. Items are
+/// highlighted with a click, or with the arrow keys and Space/Enter in a focused pane,
+/// and moved with the buttons in between.
+/// `on_change` gets the ids of the new selection.
+pub fn dual_list_select(
+    caption: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    dual_list_select_with_id(caption, &ids.control, value, on_change)
+}
+
+/// [`dual_list_select`] with an explicit id, for transfer boxes sharing a caption on one page.
+pub fn dual_list_select_with_id(
+    caption: &str,
+    id: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
+    let caption_id = format!("{}-caption", ids.control);
+
+    let validation = get_validation_state(Some(value), false, None, &ids);
+
+    rsx! {
+        div { class: "{theme.wrapper} {validation.error_class}",
+            div { id: caption_id.as_str(),
+                {caption}
+                {render_required_mark(value.is_required())}
+            }
+            {validation.message}
+            div {
+                class: theme.dual_list,
+                id: ids.control.as_str(),
+                role: "group",
+                aria_labelledby: caption_id.as_str(),
+                aria_required: value.is_required(),
+                aria_invalid: validation.aria_invalid,
+                aria_describedby: validation.aria_describedby,
+                DualList {
+                    id: ids.control.clone(),
+                    options: Rc::new(value.get_options()),
+                    selected_ids: value.get_selected_ids(),
+                    max_selected: value.get_max_selected(),
+                    on_change,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DualList(
+    id: String,
+    options: Rc<Vec<MultiSelectOption>>,
+    selected_ids: Vec<String>,
+    max_selected: Option<usize>,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let mut highlighted = use_signal(Vec::<String>::new);
+    // Position of the keyboard cursor in each pane and the id of the pane which has the focus.
+    let active_available = use_signal(|| 0usize);
+    let active_selected = use_signal(|| 0usize);
+    let mut focused = use_signal(|| None::<String>);
+
+    let translator = Translator::current();
+    let theme = Theme::current();
+
+    let mut toggle = move |option_id: &str| {
+        let mut highlighted = highlighted.write();
+        match highlighted.iter().position(|id| id == option_id) {
+            Some(index) => {
+                highlighted.remove(index);
+            }
+            None => highlighted.push(option_id.to_string()),
+        }
+    };
+
+    let render_pane = |header_id: String,
+                       header: String,
+                       items: Vec<&MultiSelectOption>,
+                       mut active: Signal<usize>| {
+        let groups = group_options(items.into_iter());
+        // Keyboard positions follow the rendered order, which is grouped.
+        let item_ids: Vec<String> = groups
+            .iter()
+            .flat_map(|(_, options)| options.iter().map(|option| option.id.clone()))
+            .collect();
+
+        let position = active().min(item_ids.len().saturating_sub(1));
+        let is_focused = focused.read().as_deref() == Some(header_id.as_str());
+        let get_option_id = |position: usize| format!("{}-option-{}", header_id, position);
+
+        let active_descendant = if item_ids.is_empty() {
+            None
+        } else {
+            Some(get_option_id(position))
+        };
+
+        let mut index = 0;
+        let mut rendered_items = Vec::with_capacity(groups.len());
+
+        for (group_index, (group, options)) in groups.iter().enumerate() {
+            let mut rendered_options = Vec::with_capacity(options.len());
+
+            for option in options.iter() {
+                let option_index = index;
+                index += 1;
+
+                let is_highlighted = highlighted.read().contains(&option.id);
+                let option_id = option.id.clone();
+
+                rendered_options.push(rsx! {
+                    div {
+                        class: if is_highlighted { theme.dual_list_item_active } else { theme.dual_list_item },
+                        style: if is_focused && option_index == position { theme.dual_list_item_focused_style } else { "" },
+                        id: get_option_id(option_index),
+                        role: "option",
+                        aria_selected: is_highlighted,
+                        onclick: move |_| {
+                            active.set(option_index);
+                            toggle(&option_id);
+                        },
+                        {option.label.as_str()}
+                    }
+                });
+            }
+
+            if group.is_empty() {
+                rendered_items.push(rsx! {
+                    {rendered_options.into_iter()}
+                });
+                continue;
+            }
+
+            let group_id = format!("{}-group-{}", header_id, group_index);
+            rendered_items.push(rsx! {
+                div { role: "group", aria_labelledby: group_id.as_str(),
+                    div {
+                        class: theme.option_group_header,
+                        id: group_id.as_str(),
+                        role: "presentation",
+                        {*group}
+                    }
+                    {rendered_options.into_iter()}
+                }
+            });
+        }
+
+        let focused_id = header_id.clone();
+
+        rsx! {
+            div { class: theme.dual_list_column,
+                div { class: theme.dual_list_header, id: header_id.as_str(), {header} }
+                div {
+                    class: theme.dual_list_pane,
+                    style: theme.dual_list_pane_style,
+                    role: "listbox",
+                    aria_multiselectable: "true",
+                    tabindex: 0,
+                    aria_labelledby: header_id.as_str(),
+                    aria_activedescendant: active_descendant,
+                    onfocus: move |_| focused.set(Some(focused_id.clone())),
+                    onblur: move |_| focused.set(None),
+                    onkeydown: move |e: KeyboardEvent| {
+                        let Some(last) = item_ids.len().checked_sub(1) else {
+                            return;
+                        };
+
+                        match e.key() {
+                            Key::ArrowDown => {
+                                e.prevent_default();
+                                active.set((position + 1).min(last));
+                            }
+                            Key::ArrowUp => {
+                                e.prevent_default();
+                                active.set(position.saturating_sub(1));
+                            }
+                            Key::Enter => {
+                                e.prevent_default();
+                                toggle(&item_ids[position]);
+                            }
+                            Key::Character(c) if c == " " => {
+                                e.prevent_default();
+                                toggle(&item_ids[position]);
+                            }
+                            _ => {}
+                        }
+                    },
+                    {rendered_items.into_iter()}
+                }
+            }
+        }
+    };
+
+    let available: Vec<&MultiSelectOption> =
+        options.iter().filter(|option| !option.selected).collect();
+    let selected: Vec<&MultiSelectOption> = selected_ids
+        .iter()
+        .filter_map(|id| options.iter().find(|option| &option.id == id))
+        .collect();
+
+    // Adding must not take the selection over the max, so all highlighted items have to fit.
+    let to_add = available
+        .iter()
+        .filter(|option| highlighted.read().contains(&option.id))
+        .count();
+    let can_add = to_add > 0
+        && max_selected
+            .map(|max| selected_ids.len() + to_add <= max)
+            .unwrap_or(true);
+    let can_remove = selected
+        .iter()
+        .any(|option| highlighted.read().contains(&option.id));
+
+    let available_pane = render_pane(
+        format!("{}-available", id),
+        translator.translate(&MessageKey::Available),
+        available,
+        active_available,
+    );
+    let selected_pane = render_pane(
+        format!("{}-selected", id),
+        translator.translate(&MessageKey::Selected),
+        selected,
+        active_selected,
+    );
+
+    let add_options = options.clone();
+    let add_selected_ids = selected_ids.clone();
+
+    rsx! {
+        {available_pane}
+        div { class: theme.dual_list_buttons,
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                disabled: !can_add,
+                onclick: move |_| {
+                    if !can_add {
+                        return;
+                    }
+
+                    let mut ids = add_selected_ids.clone();
+                    for option in add_options.iter() {
+                        if !option.selected && highlighted.read().contains(&option.id) {
+                            ids.push(option.id.clone());
+                        }
+                    }
+
+                    highlighted.set(Vec::new());
+                    on_change.call(ids);
+                },
+                {translator.translate(&MessageKey::AddSelected)}
+            }
+            button {
+                class: theme.input_button,
+                r#type: "button",
+                disabled: !can_remove,
+                onclick: move |_| {
+                    let ids = selected_ids
+                        .iter()
+                        .filter(|id| !highlighted.read().contains(id))
+                        .cloned()
+                        .collect();
+
+                    highlighted.set(Vec::new());
+                    on_change.call(ids);
+                },
+                {translator.translate(&MessageKey::RemoveSelected)}
+            }
+        }
+        {selected_pane}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+    use crate::test_utils::*;
+    use crate::types::*;
+
+    fn create_value(selected: &[&str]) -> SelectedValues<Account> {
+        SelectedValues::new_with_selected(
+            vec![create_accounts(&["L1", "L2", "D1"]).into()],
+            selected,
+        )
+    }
+
+    fn render(mut value: Signal<SelectedValues<Account>>) -> Element {
+        let list = dual_list_select(
+            "Accounts",
+            &*value.read(),
+            EventHandler::new(move |ids: Vec<String>| value.write().set_selected_ids(&ids)),
+        );
+
+        let selected = value.read().get_selected_ids().join(",");
+        render_with_value(list, selected)
+    }
+
+    fn app() -> Element {
+        render(use_signal(|| create_value(&[])))
+    }
+
+    #[test]
+    fn moves_highlighted_items_to_selected() {
+        let mut harness = ComponentHarness::new(app);
+
+        let html = harness.render_html();
+        assert!(html.contains("role=\"listbox\" aria-multiselectable=\"true\""));
+        assert!(html.contains("id=\"edit-accounts-available\">Available</div>"));
+        assert_eq!(html.matches("disabled=\"true\"").count(), 2);
+
+        harness.fire_click_at(0);
+        harness.fire_click_at(2);
+        let html = harness.render_html();
+        assert_eq!(html.matches("aria-selected=\"true\"").count(), 2);
+        assert_eq!(html.matches("disabled=\"true\"").count(), 1);
+
+        harness.fire_click_at(3);
+        let html = harness.render_html();
+        assert!(html.contains("value: L1,D1"));
+        assert!(!html.contains("aria-selected=\"true\""));
+    }
+
+    #[test]
+    fn highlights_items_with_keyboard() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_focus_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("tabindex=\"0\""));
+        assert!(html.contains("aria-activedescendant=\"edit-accounts-available-option-0\""));
+
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::empty());
+        harness.fire_key_down_at(0, Key::Character(" ".to_string()), Modifiers::empty());
+        let html = harness.render_html();
+        assert!(html.contains("aria-activedescendant=\"edit-accounts-available-option-1\""));
+        assert!(html.contains(
+            "id=\"edit-accounts-available-option-1\" role=\"option\" aria-selected=\"true\""
+        ));
+
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::empty());
+        harness.fire_key_down_at(0, Key::ArrowDown, Modifiers::empty());
+        harness.fire_key_down_at(0, Key::Enter, Modifiers::empty());
+        assert_eq!(
+            harness
+                .render_html()
+                .matches("aria-selected=\"true\"")
+                .count(),
+            2
+        );
+
+        // Add is the only enabled button.
+        harness.fire_click_at(3);
+        assert!(harness.render_html().contains("value: L2,D1"));
+    }
+
+    fn grouped_app() -> Element {
+        render(use_signal(|| {
+            SelectedValues::new_with_selected(create_account_groups(), &["D1", "L2"])
+        }))
+    }
+
+    #[test]
+    fn renders_group_headers_in_both_panes() {
+        let mut harness = ComponentHarness::new(grouped_app);
+
+        let html = harness.render_html();
+        assert!(
+            html.contains(
+                "id=\"edit-accounts-available-group-0\" role=\"presentation\">Live</div>"
+            )
+        );
+        assert!(!html.contains("id=\"edit-accounts-available-group-1\""));
+        assert!(
+            html.contains("id=\"edit-accounts-selected-group-0\" role=\"presentation\">Demo</div>")
+        );
+        assert!(
+            html.contains("id=\"edit-accounts-selected-group-1\" role=\"presentation\">Live</div>")
+        );
+
+        // The cursor follows the grouped order: Demo D1 first, then Live L2.
+        harness.fire_focus_at(1);
+        harness.fire_key_down_at(1, Key::ArrowDown, Modifiers::empty());
+        harness.fire_key_down_at(1, Key::Enter, Modifiers::empty());
+        assert!(harness.render_html().contains(
+            "id=\"edit-accounts-selected-option-1\" role=\"option\" aria-selected=\"true\">L2</div>"
+        ));
+    }
+
+    fn limited_app() -> Element {
+        render(use_signal(|| create_value(&["L1"]).set_max_selected(2)))
+    }
+
+    #[test]
+    fn does_not_add_over_max_selected() {
+        let mut harness = ComponentHarness::new(limited_app);
+
+        // L2, D1, Add, Remove, then the selected L1.
+        harness.fire_click_at(0);
+        assert_eq!(
+            harness.render_html().matches("disabled=\"true\"").count(),
+            1
+        );
+
+        harness.fire_click_at(1);
+        assert_eq!(
+            harness.render_html().matches("disabled=\"true\"").count(),
+            2
+        );
+
+        harness.fire_click_at(2);
+        assert!(harness.render_html().contains("value: L1<"));
+
+        harness.fire_click_at(1);
+        harness.fire_click_at(2);
+        assert!(harness.render_html().contains("value: L1,L2<"));
+    }
+
+    fn selected_app() -> Element {
+        render(use_signal(|| create_value(&["L1", "D1"])))
+    }
+
+    #[test]
+    fn removes_highlighted_items_from_selected() {
+        let mut harness = ComponentHarness::new(selected_app);
+
+        // L2, Add, Remove, then the selected L1 and D1.
+        harness.fire_click_at(3);
+        harness.fire_click_at(2);
+        assert!(harness.render_html().contains("value: D1"));
+    }
+
+    fn app_with_id() -> Element {
+        let value = use_signal(|| create_value(&[]));
+
+        dual_list_select_with_id(
+            "Accounts",
+            "live-accounts",
+            &*value.read(),
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("id=\"live-accounts\""));
+        assert!(html.contains("aria-labelledby=\"live-accounts-caption\""));
+    }
+}
@@ -0,0 +1,8 @@
+mod select_chips;
+pub use select_chips::*;
+mod checkbox_list;
+pub use checkbox_list::*;
+mod dual_list_select;
+pub use dual_list_select::*;
+mod option_groups;
+use option_groups::*;
@@ -0,0 +1,18 @@
+use crate::types::MultiSelectOption;
+
+/// Collects options by their group. Groups keep the order they first appear in,
+/// so selected items in pick order do not repeat a group header.
+pub(crate) fn group_options<'s>(
+    options: impl Iterator<Item = &'s MultiSelectOption>,
+) -> Vec<(&'static str, Vec<&'s MultiSelectOption>)> {
+    let mut result: Vec<(&'static str, Vec<&'s MultiSelectOption>)> = Vec::new();
+
+    for option in options {
+        match result.iter_mut().find(|(group, _)| *group == option.group) {
+            Some((_, items)) => items.push(option),
+            None => result.push((option.group, vec![option])),
+        }
+    }
+
+    result
+}
@@ -0,0 +1,189 @@
+use super::group_options;
+use crate::components::{ControlIds, NULL_VALUE, get_validation_state, render_required_mark};
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use crate::types::MultiSelectValue;
+use dioxus::prelude::*;
+
+/// Selected items as removable tags, followed by a `select` which adds one of the rest.
+/// `on_change` gets the ids of the new selection.
+pub fn select_chips(
+    caption: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    select_chips_with_id(caption, &ids.control, value, on_change)
+}
+
+/// [`select_chips`] with an explicit id, for chips sharing a caption on one page.
+pub fn select_chips_with_id(
+    caption: &str,
+    id: &str,
+    value: &impl MultiSelectValue,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    let translator = Translator::current();
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
+
+    let options = value.get_options();
+    let selected_ids = value.get_selected_ids();
+
+    let validation = get_validation_state(Some(value), false, None, &ids);
+
+    let chips = selected_ids
+        .iter()
+        .filter_map(|id| options.iter().find(|option| &option.id == id))
+        .map(|option| {
+            let aria_label = translator.translate(&MessageKey::RemoveItem(&option.label));
+            let remaining: Vec<String> = selected_ids
+                .iter()
+                .filter(|id| *id != &option.id)
+                .cloned()
+                .collect();
+
+            rsx! {
+                span { class: theme.chip,
+                    {option.label.as_str()}
+                    button {
+                        class: theme.chip_remove,
+                        r#type: "button",
+                        aria_label,
+                        onclick: move |_| on_change.call(remaining.clone()),
+                        "×"
+                    }
+                }
+            }
+        });
+
+    let available = group_options(options.iter().filter(|option| !option.selected));
+    let disabled = available.is_empty() || !value.can_select_more();
+
+    let groups = available.into_iter().map(|(group, items)| {
+        let items = items.into_iter().map(|option| {
+            rsx! {
+                option { value: option.id.as_str(), {option.label.as_str()} }
+            }
+        });
+
+        if group.is_empty() {
+            rsx! {
+                {items}
+            }
+        } else {
+            rsx! {
+                optgroup { label: group, {items} }
+            }
+        }
+    });
+
+    let add_item = translator.translate(&MessageKey::AddItem);
+
+    rsx! {
+        div { class: "{theme.wrapper} {validation.error_class}",
+            label { r#for: ids.control.as_str(),
+                {caption}
+                {render_required_mark(value.is_required())}
+            }
+            {validation.message}
+            div { class: theme.chips, {chips} }
+            select {
+                id: ids.control.as_str(),
+                class: theme.select,
+                disabled,
+                aria_required: value.is_required(),
+                aria_invalid: validation.aria_invalid,
+                aria_describedby: validation.aria_describedby,
+                value: NULL_VALUE,
+                onchange: move |e| {
+                    let id = e.value();
+                    if id.as_str() == NULL_VALUE {
+                        return;
+                    }
+
+                    let mut ids = selected_ids.clone();
+                    ids.push(id);
+                    on_change.call(ids);
+                },
+                option { value: NULL_VALUE, {add_item} }
+                {groups}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::types::*;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| {
+            SelectEnumValues::new(vec![TimeOffset::Utc])
+                .set_min_selected(1)
+                .set_max_selected(2)
+        });
+
+        let chips = select_chips(
+            "Time offsets",
+            &*value.read(),
+            EventHandler::new(move |ids: Vec<String>| value.write().set_selected_ids(&ids)),
+        );
+
+        let selected = value.read().get_selected_ids().join(",");
+
+        rsx! {
+            {chips}
+            div { "value: {selected}" }
+        }
+    }
+
+    #[test]
+    fn adds_and_removes_chips() {
+        let mut harness = ComponentHarness::new(app);
+
+        let html = harness.render_html();
+        assert!(html.contains("aria-label=\"Remove +00:00\""));
+        assert!(html.contains(">Add…</option>"));
+        assert!(!html.contains("<option value=\"+00:00\""));
+
+        harness.fire_change("+02:00");
+        let html = harness.render_html();
+        assert!(html.contains("value: +00:00,+02:00"));
+        assert!(html.contains("disabled=\"true\""));
+
+        harness.fire_click_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("value: +02:00"));
+        assert!(!html.contains("disabled=\"true\""));
+    }
+
+    #[test]
+    fn reports_blank_selection_once_touched() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_click_at(0);
+        let html = harness.render_html();
+        assert!(html.contains("value: </div>"));
+        assert!(html.contains("role=\"alert\""));
+        assert!(html.contains("aria-invalid=\"true\""));
+    }
+
+    fn app_with_id() -> Element {
+        select_chips_with_id(
+            "Time offsets",
+            "live-offsets",
+            &SelectEnumValues::new(vec![TimeOffset::Utc]),
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("for=\"live-offsets\""));
+        assert!(html.contains("id=\"live-offsets\""));
+    }
+}
@@ -4,5 +4,7 @@ mod table_item;
 pub use table_item::*;
 mod render_table_rc;
 pub use render_table_rc::*;
+mod table_context_menu;
+pub use table_context_menu::*;
 //mod render_read_only_table;
 //pub use render_read_only_table::*;
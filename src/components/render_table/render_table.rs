@@ -1,12 +1,15 @@
-use super::TableItem;
+use super::{TableContextMenu, TableItem, open_context_menu};
 use dioxus::prelude::*;
-use std::marker::PhantomData;
+use std::{marker::PhantomData, rc::Rc};
+
+type CloneItem<TItem> = fn(&TItem) -> Rc<TItem>;
 
 pub struct RenderTable<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>> {
     phantom: PhantomData<TItem>,
     table_classes: Vec<&'static str>,
     items: MyIterator,
     wrapped_div: Option<&'static str>,
+    context_menu: Option<(TableContextMenu<TItem>, CloneItem<TItem>)>,
 }
 
 impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
@@ -18,6 +21,7 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
             table_classes: Default::default(),
             items,
             wrapped_div: Default::default(),
+            context_menu: None,
         }
     }
 
@@ -31,6 +35,14 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
         self
     }
 
+    pub fn with_context_menu(mut self, context_menu: TableContextMenu<TItem>) -> Self
+    where
+        TItem: Clone,
+    {
+        self.context_menu = Some((context_menu, |itm| Rc::new(itm.clone())));
+        self
+    }
+
     fn render_content(
         self,
         header_action: Option<Element>,
@@ -44,6 +56,14 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
             }
         });
 
+        let context_menu_header = if self.context_menu.is_some() {
+            rsx! {
+                th {}
+            }
+        } else {
+            rsx! {}
+        };
+
         let (header_action, has_action) = if let Some(header_action) = header_action {
             let result = rsx! {
 
@@ -56,6 +76,9 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
             (rsx! {}, false)
         };
 
+        let context_menu = self.context_menu.as_ref();
+        let context_menu_state = context_menu.map(|(context_menu, _)| context_menu.get_state());
+
         let items = self.items.into_iter().map(|itm| {
             let items = (0..TItem::COLUMNS_AMOUNT).into_iter().map(|index| {
                 let value = itm.get_value(index);
@@ -79,18 +102,47 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
                 }
             });
 
+            let menu_key = context_menu.map(|(context_menu, _)| context_menu.get_key(itm));
+
+            let context_menu_cell = match (context_menu, menu_key.as_deref()) {
+                (Some((context_menu, clone_item)), Some(key)) => {
+                    let popup = context_menu.render_popup(key, || clone_item(itm));
+                    rsx! {
+                        td {
+                            {context_menu.render_row_trigger(key)}
+                            {popup}
+                        }
+                    }
+                }
+                _ => rsx! {},
+            };
+
             if has_action {
                 let item = line_action(itm);
 
                 rsx! {
                     tr {
+                        oncontextmenu: move |e| {
+                            if let (Some(state), Some(key)) = (context_menu_state, menu_key.clone()) {
+                                open_context_menu(state, key, &e);
+                            }
+                        },
                         {items}
                         td { {item} }
+                        {context_menu_cell}
                     }
                 }
             } else {
                 rsx! {
-                    tr { {items} }
+                    tr {
+                        oncontextmenu: move |e| {
+                            if let (Some(state), Some(key)) = (context_menu_state, menu_key.clone()) {
+                                open_context_menu(state, key, &e);
+                            }
+                        },
+                        {items}
+                        {context_menu_cell}
+                    }
                 }
             }
         });
@@ -101,6 +153,7 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
                     tr {
                         {header}
                         {header_action}
+                        {context_menu_header}
                     }
                 }
 
@@ -128,3 +181,144 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s TItem>>
         self.render_content(None, |_| rsx! {})
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{ContextMenuAction, ValueToRender};
+    use crate::test_utils::*;
+
+    #[derive(Clone)]
+    struct TestItem {
+        id: &'static str,
+        amount: i32,
+    }
+
+    impl TableItem for TestItem {
+        const HEADER: &'static [&'static str] = &["Id", "Amount"];
+
+        const COLUMNS_AMOUNT: usize = 2;
+
+        fn get_value<'s>(&'s self, index: usize) -> ValueToRender<'s> {
+            match index {
+                0 => self.id.into(),
+                _ => self.amount.to_string().into(),
+            }
+        }
+    }
+
+    fn app() -> Element {
+        let items = [
+            TestItem { id: "a", amount: 1 },
+            TestItem { id: "b", amount: 2 },
+        ];
+
+        RenderTable::new(items.iter())
+            .with_class(&["table", "table-striped"])
+            .with_wrapped_div("table-wrapper")
+            .render_line_with_actions(rsx! { "Actions" }, |itm| {
+                rsx! {
+                    button { "Edit {itm.id}" }
+                }
+            })
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("render_table", &harness.render_html());
+    }
+
+    fn menu_app() -> Element {
+        let mut items = use_signal(|| {
+            vec![
+                TestItem { id: "a", amount: 1 },
+                TestItem { id: "b", amount: 2 },
+            ]
+        });
+        let state = use_signal(|| None);
+        let log = use_signal(Vec::<String>::new);
+
+        let log_action = move |action: &'static str| {
+            move |itm: &TestItem| {
+                let mut log = log;
+                log.write().push(format!("{} {}", action, itm.id));
+            }
+        };
+
+        let menu = TableContextMenu::new(state, |itm: &TestItem| itm.id.to_string())
+            .with_action(ContextMenuAction::new("Edit", log_action("edit")))
+            .with_action(
+                ContextMenuAction::new("Archive", log_action("archive"))
+                    .with_enabled(|itm: &TestItem| itm.amount > 1),
+            )
+            .with_action(ContextMenuAction::new("Delete", log_action("delete")).set_danger(true));
+
+        let items_ref = items.read();
+        let table = RenderTable::new(items_ref.iter())
+            .with_context_menu(menu)
+            .render();
+
+        rsx! {
+            button { onclick: move |_| items.write().reverse(), "Reverse" }
+            {table}
+            div { "log: {log.read().join(\",\")}" }
+        }
+    }
+
+    #[test]
+    fn opens_menu_and_triggers_action() {
+        let mut harness = ComponentHarness::new(menu_app);
+        assert!(!harness.render_html().contains("role=\"menu\""));
+
+        harness.fire_click_at(2);
+        let html = harness.render_html();
+        assert!(html.contains("role=\"menu\""));
+        assert_eq!(html.matches("aria-expanded=\"true\"").count(), 1);
+
+        harness.fire_click_at(6);
+        let html = harness.render_html();
+        assert!(html.contains("log: delete b<"));
+        assert!(!html.contains("role=\"menu\""));
+    }
+
+    fn get_focused_item(html: &str) -> &str {
+        let start = html.find("tabindex=\"0\"").expect("no focused item");
+        let html = &html[start..];
+        let end = html.find("</button>").unwrap();
+        html[..end].rsplit('>').next().unwrap()
+    }
+
+    #[test]
+    fn moves_focus_with_arrow_keys_and_closes_on_escape() {
+        let mut harness = ComponentHarness::new(menu_app);
+        harness.fire_click_at(1);
+        assert_eq!(get_focused_item(&harness.render_html()), "Edit");
+
+        // Archive is disabled for the item `a`.
+        harness.fire_key_down_at(2, Key::ArrowDown, Modifiers::empty());
+        assert_eq!(get_focused_item(&harness.render_html()), "Delete");
+
+        harness.fire_key_down_at(2, Key::ArrowDown, Modifiers::empty());
+        assert_eq!(get_focused_item(&harness.render_html()), "Edit");
+
+        harness.fire_key_down_at(2, Key::ArrowUp, Modifiers::empty());
+        assert_eq!(get_focused_item(&harness.render_html()), "Delete");
+
+        harness.fire_key_down_at(2, Key::Escape, Modifiers::empty());
+        assert!(!harness.render_html().contains("role=\"menu\""));
+    }
+
+    #[test]
+    fn keeps_menu_with_its_item_when_rows_move() {
+        let mut harness = ComponentHarness::new(menu_app);
+        harness.fire_click_at(2);
+        harness.fire_click_at(0);
+
+        let html = harness.render_html();
+        let row_b = html.find("<td>b</td>").unwrap();
+        let row_a = html.find("<td>a</td>").unwrap();
+        let menu = html.find("role=\"menu\"").unwrap();
+        assert!(row_b < menu && menu < row_a);
+    }
+}
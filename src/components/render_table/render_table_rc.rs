@@ -1,4 +1,4 @@
-use super::TableItem;
+use super::{TableContextMenu, TableItem, open_context_menu};
 use dioxus::prelude::*;
 use std::{marker::PhantomData, rc::Rc};
 
@@ -8,6 +8,7 @@ pub struct RenderTableRc<'s, TItem: TableItem + 'static, MyIterator: Iterator<It
     table_classes: Vec<&'static str>,
     items: MyIterator,
     wrapped_div: Option<&'static str>,
+    context_menu: Option<TableContextMenu<TItem>>,
 }
 
 impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
@@ -19,6 +20,7 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
             table_classes: Default::default(),
             items,
             wrapped_div: Default::default(),
+            context_menu: None,
         }
     }
 
@@ -32,6 +34,11 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
         self
     }
 
+    pub fn with_context_menu(mut self, context_menu: TableContextMenu<TItem>) -> Self {
+        self.context_menu = Some(context_menu);
+        self
+    }
+
     fn render_content(
         self,
         header_action: Option<Element>,
@@ -45,6 +52,14 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
             }
         });
 
+        let context_menu_header = if self.context_menu.is_some() {
+            rsx! {
+                th {}
+            }
+        } else {
+            rsx! {}
+        };
+
         let (header_action, has_action) = if let Some(header_action) = header_action {
             let result = rsx! {
 
@@ -57,6 +72,9 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
             (rsx! {}, false)
         };
 
+        let context_menu = self.context_menu.as_ref();
+        let context_menu_state = context_menu.map(|context_menu| context_menu.get_state());
+
         let items = self.items.into_iter().map(|itm| {
             let items = (0..TItem::COLUMNS_AMOUNT).into_iter().map(|index| {
                 let value = itm.get_value(index);
@@ -80,18 +98,47 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
                 }
             });
 
+            let menu_key = context_menu.map(|context_menu| context_menu.get_key(itm));
+
+            let context_menu_cell = match (context_menu, menu_key.as_deref()) {
+                (Some(context_menu), Some(key)) => {
+                    let popup = context_menu.render_popup(key, || itm.clone());
+                    rsx! {
+                        td {
+                            {context_menu.render_row_trigger(key)}
+                            {popup}
+                        }
+                    }
+                }
+                _ => rsx! {},
+            };
+
             if has_action {
                 let item = line_action(itm);
 
                 rsx! {
                     tr {
+                        oncontextmenu: move |e| {
+                            if let (Some(state), Some(key)) = (context_menu_state, menu_key.clone()) {
+                                open_context_menu(state, key, &e);
+                            }
+                        },
                         {items}
                         td { {item} }
+                        {context_menu_cell}
                     }
                 }
             } else {
                 rsx! {
-                    tr { {items} }
+                    tr {
+                        oncontextmenu: move |e| {
+                            if let (Some(state), Some(key)) = (context_menu_state, menu_key.clone()) {
+                                open_context_menu(state, key, &e);
+                            }
+                        },
+                        {items}
+                        {context_menu_cell}
+                    }
                 }
             }
         });
@@ -102,6 +149,7 @@ impl<'s, TItem: TableItem + 'static, MyIterator: Iterator<Item = &'s Rc<TItem>>>
                     tr {
                         {header}
                         {header_action}
+                        {context_menu_header}
                     }
                 }
 
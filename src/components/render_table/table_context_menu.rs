@@ -0,0 +1,312 @@
+use crate::i18n::{MessageKey, Translator};
+use crate::theme::Theme;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// Opened menu: the key of its item and the cursor position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextMenuPosition {
+    pub key: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+pub struct ContextMenuAction<TItem: 'static> {
+    label: &'static str,
+    icon: Option<&'static str>,
+    danger: bool,
+    enabled: Rc<dyn Fn(&TItem) -> bool>,
+    handler: Rc<dyn Fn(&TItem)>,
+}
+
+impl<TItem: 'static> ContextMenuAction<TItem> {
+    pub fn new(label: &'static str, handler: impl Fn(&TItem) + 'static) -> Self {
+        Self {
+            label,
+            icon: None,
+            danger: false,
+            enabled: Rc::new(|_| true),
+            handler: Rc::new(handler),
+        }
+    }
+
+    pub fn with_icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn set_danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: impl Fn(&TItem) -> bool + 'static) -> Self {
+        self.enabled = Rc::new(enabled);
+        self
+    }
+
+    pub fn is_enabled(&self, item: &TItem) -> bool {
+        (self.enabled)(item)
+    }
+}
+
+/// Per-row menu opened by right click on a row or by the kebab button of the row.
+///
+/// The opened item and the cursor position live in the `state` signal owned by the page,
+/// so the menu survives re-renders of the table. `get_key` identifies the item of a row,
+/// so the menu stays with its item when rows are inserted, removed or sorted.
+pub struct TableContextMenu<TItem: 'static> {
+    actions: Vec<ContextMenuAction<TItem>>,
+    state: Signal<Option<ContextMenuPosition>>,
+    get_key: Rc<dyn Fn(&TItem) -> String>,
+}
+
+impl<TItem: 'static> TableContextMenu<TItem> {
+    pub fn new(
+        state: Signal<Option<ContextMenuPosition>>,
+        get_key: impl Fn(&TItem) -> String + 'static,
+    ) -> Self {
+        Self {
+            actions: Vec::new(),
+            state,
+            get_key: Rc::new(get_key),
+        }
+    }
+
+    pub fn with_action(mut self, action: ContextMenuAction<TItem>) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub(crate) fn get_key(&self, item: &TItem) -> String {
+        (self.get_key)(item)
+    }
+
+    pub(crate) fn render_row_trigger(&self, key: &str) -> Element {
+        let mut state = self.state;
+        let key = key.to_string();
+        let aria_label = Translator::current().translate(&MessageKey::RowActions);
+        rsx! {
+            button {
+                class: Theme::current().menu_trigger,
+                r#type: "button",
+                aria_haspopup: "menu",
+                aria_expanded: self.is_opened(&key),
+                aria_label,
+                onclick: move |e| open_context_menu(state, key.clone(), &e),
+                onkeydown: move |e| {
+                    if e.key() == Key::Escape && state.peek().is_some() {
+                        state.set(None);
+                    }
+                },
+                "⋮"
+            }
+        }
+    }
+
+    pub(crate) fn get_state(&self) -> Signal<Option<ContextMenuPosition>> {
+        self.state
+    }
+
+    pub(crate) fn is_opened(&self, key: &str) -> bool {
+        match self.state.read().as_ref() {
+            Some(position) => position.key == key,
+            None => false,
+        }
+    }
+
+    pub(crate) fn render_popup(&self, key: &str, item: impl FnOnce() -> Rc<TItem>) -> Element {
+        let (x, y) = match self.state.read().as_ref() {
+            Some(position) if position.key == key => (position.x, position.y),
+            _ => return rsx! {},
+        };
+
+        let item = item();
+
+        let entries: Vec<MenuEntry> = self
+            .actions
+            .iter()
+            .map(|action| MenuEntry {
+                label: action.label,
+                icon: action.icon,
+                danger: action.danger,
+                enabled: action.is_enabled(&item),
+            })
+            .collect();
+
+        let handlers: Vec<_> = self
+            .actions
+            .iter()
+            .map(|action| action.handler.clone())
+            .collect();
+
+        rsx! {
+            ContextMenuPopup {
+                x,
+                y,
+                state: self.state,
+                entries,
+                on_select: move |index: usize| {
+                    if let Some(handler) = handlers.get(index) {
+                        handler(&item);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MenuEntry {
+    label: &'static str,
+    icon: Option<&'static str>,
+    danger: bool,
+    enabled: bool,
+}
+
+/// Menu with a roving focus: the first enabled item is focused on open, ArrowUp/ArrowDown
+/// move the focus over the enabled items and Home/End jump to the ends.
+/// Escape and Tab close the menu wherever the focus is inside the popup.
+#[component]
+fn ContextMenuPopup(
+    x: f64,
+    y: f64,
+    state: Signal<Option<ContextMenuPosition>>,
+    entries: Vec<MenuEntry>,
+    on_select: EventHandler<usize>,
+) -> Element {
+    let enabled: Vec<usize> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.enabled)
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut focused = use_signal(|| enabled.first().copied());
+    let mut mounted = use_signal(Vec::<(usize, Rc<MountedData>)>::new);
+
+    let mut move_focus = move |index: usize| {
+        focused.set(Some(index));
+
+        let element = mounted
+            .peek()
+            .iter()
+            .find(|(mounted_index, _)| *mounted_index == index)
+            .map(|(_, element)| element.clone());
+
+        if let Some(element) = element {
+            spawn(async move {
+                let _ = element.set_focus(true).await;
+            });
+        }
+    };
+
+    let theme = Theme::current();
+
+    let items = entries.iter().enumerate().map(|(index, entry)| {
+        let class = if entry.danger {
+            theme.menu_item_danger
+        } else {
+            theme.menu_item
+        };
+
+        let icon = match entry.icon {
+            Some(icon) => rsx! {
+                span { aria_hidden: "true", style: theme.menu_icon_style, {icon} }
+            },
+            None => rsx! {},
+        };
+
+        let tabindex = if focused() == Some(index) { "0" } else { "-1" };
+
+        rsx! {
+            li { role: "none",
+                button {
+                    class,
+                    r#type: "button",
+                    role: "menuitem",
+                    tabindex,
+                    disabled: !entry.enabled,
+                    aria_disabled: !entry.enabled,
+                    onmounted: move |e| {
+                        let element = e.data();
+                        mounted.write().push((index, element.clone()));
+
+                        if *focused.peek() == Some(index) {
+                            spawn(async move {
+                                let _ = element.set_focus(true).await;
+                            });
+                        }
+                    },
+                    onclick: move |_| {
+                        state.set(None);
+                        on_select.call(index);
+                    },
+                    {icon}
+                    {entry.label}
+                }
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            onkeydown: move |e| {
+                let position = focused().and_then(|index| enabled.iter().position(|i| *i == index));
+                let next = match e.key() {
+                    Key::Escape | Key::Tab => {
+                        e.prevent_default();
+                        state.set(None);
+                        return;
+                    }
+                    Key::ArrowDown => match position {
+                        Some(position) => enabled.get((position + 1) % enabled.len()),
+                        None => enabled.first(),
+                    },
+                    Key::ArrowUp => match position {
+                        Some(position) => {
+                            enabled.get((position + enabled.len() - 1) % enabled.len())
+                        }
+                        None => enabled.last(),
+                    },
+                    Key::Home => enabled.first(),
+                    Key::End => enabled.last(),
+                    _ => return,
+                };
+
+                e.prevent_default();
+                if let Some(next) = next {
+                    move_focus(*next);
+                }
+            },
+            div {
+                style: "position: fixed; inset: 0; z-index: 1000;",
+                onclick: move |_| state.set(None),
+                oncontextmenu: move |e| {
+                    e.prevent_default();
+                    state.set(None);
+                },
+            }
+            ul {
+                class: theme.menu,
+                role: "menu",
+                style: "position: fixed; left: {x}px; top: {y}px; z-index: 1001;",
+                {items}
+            }
+        }
+    }
+}
+
+pub(crate) fn open_context_menu(
+    mut state: Signal<Option<ContextMenuPosition>>,
+    key: String,
+    e: &MouseEvent,
+) {
+    e.prevent_default();
+    let point = e.client_coordinates();
+    state.set(Some(ContextMenuPosition {
+        key,
+        x: point.x,
+        y: point.y,
+    }));
+}
@@ -1,3 +1,6 @@
+use super::ControlIds;
+use crate::i18n::*;
+use crate::theme::Theme;
 use crate::types::*;
 use dioxus::prelude::*;
 use rust_extensions::AsStr;
@@ -10,22 +13,40 @@ pub fn select_enum_value<
     caption: &str,
     value_selector: TEnumIterator,
     on_input: EventHandler<TItem>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    select_enum_value_with_id(caption, &ids.control, value_selector, on_input)
+}
+
+/// [`select_enum_value`] with an explicit id, for selects sharing a caption on one page.
+pub fn select_enum_value_with_id<
+    TItem: Eq + Clone + Default + 'static + AsStr + FromStr,
+    TEnumIterator: EnumIterator<TItem = TItem>,
+>(
+    caption: &str,
+    id: &str,
+    value_selector: TEnumIterator,
+    on_input: EventHandler<TItem>,
 ) -> Element {
     let selected = value_selector.get_value();
+    let translator = Translator::current();
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
 
     let items = TEnumIterator::get_all().into_iter().map(|itm| {
         let selected = itm == &selected;
+        let name = translator.translate(&MessageKey::enum_value::<TEnumIterator>(itm.as_str()));
         rsx! {
-            option { selected, value: itm.as_str(), {itm.as_str()} }
+            option { selected, value: itm.as_str(), {name} }
         }
     });
 
     rsx! {
-        div { class: "edit-wrapper",
-            label { {caption} }
+        div { class: theme.wrapper,
+            label { r#for: ids.control.as_str(), {caption} }
             select {
-
-                class: "form-select",
+                id: ids.control.as_str(),
+                class: theme.select,
                 onchange: move |e| {
                     let value = e.value();
                     let value = match TItem::from_str(value.as_str()).ok() {
@@ -40,3 +61,53 @@ pub fn select_enum_value<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::*;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| TimeOffset::Utc);
+        let select = select_enum_value(
+            "Time offset",
+            *value.read(),
+            EventHandler::new(move |offset| value.set(offset)),
+        );
+
+        rsx! {
+            {select}
+            div { "value: {value.read().as_str()}" }
+        }
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("select_enum_value", &harness.render_html());
+    }
+
+    #[test]
+    fn emits_selected_value() {
+        let mut harness = ComponentHarness::new(app);
+        harness.fire_change("+03:00");
+        assert!(harness.render_html().contains("value: +03:00"));
+    }
+
+    fn app_with_id() -> Element {
+        select_enum_value_with_id(
+            "Time offset",
+            "live-offset",
+            TimeOffset::Utc,
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("for=\"live-offset\""));
+        assert!(html.contains("id=\"live-offset\""));
+    }
+}
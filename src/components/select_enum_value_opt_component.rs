@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
+use super::ControlIds;
+use crate::i18n::*;
+use crate::theme::Theme;
 use crate::types::*;
 use dioxus::prelude::*;
 use rust_extensions::AsStr;
 
-const NULL_VALUE: &'static str = "---NULL---";
+pub(crate) const NULL_VALUE: &'static str = "---NULL---";
 
 pub fn select_enum_value_opt<
     TItem: Eq + Clone + 'static + AsStr + FromStr + EnumIterator<TItem = TItem>,
@@ -12,26 +15,46 @@ pub fn select_enum_value_opt<
     caption: &str,
     value_selector: &SelectEnumValueOpt<TItem>,
     on_input: EventHandler<Option<TItem>>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    select_enum_value_opt_with_id(caption, &ids.control, value_selector, on_input)
+}
+
+/// [`select_enum_value_opt`] with an explicit id, for selects sharing a caption on one page.
+pub fn select_enum_value_opt_with_id<
+    TItem: Eq + Clone + 'static + AsStr + FromStr + EnumIterator<TItem = TItem>,
+>(
+    caption: &str,
+    id: &str,
+    value_selector: &SelectEnumValueOpt<TItem>,
+    on_input: EventHandler<Option<TItem>>,
 ) -> Element {
     let selected = value_selector.get_value();
+    let translator = Translator::current();
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
 
     let items = TItem::get_all().into_iter().map(|itm| {
         let selected = Some(itm) == selected;
+        let name = translator.translate(&MessageKey::enum_value::<TItem>(itm.as_str()));
         rsx! {
-            option { selected, value: itm.as_str(), {itm.as_str()} }
+            option { selected, value: itm.as_str(), {name} }
         }
     });
 
+    let not_selected = translator.translate(&MessageKey::NotSelected);
+
     let selected_value = match selected {
         Some(value) => value.as_str(),
         None => NULL_VALUE,
     };
     rsx! {
-        div { class: "edit-wrapper",
-            label { {caption} }
+        div { class: theme.wrapper,
+            label { r#for: ids.control.as_str(), {caption} }
             select {
-
-                class: "form-select",
+                id: ids.control.as_str(),
+                aria_required: value_selector.is_required(),
+                class: theme.select,
                 onchange: move |e| {
                     let value = e.value();
                     let value = if value.as_str() == NULL_VALUE {
@@ -42,9 +65,85 @@ pub fn select_enum_value_opt<
                     on_input.call(value);
                 },
                 value: selected_value,
-                option { value: NULL_VALUE, "Not selected" }
+                option { value: NULL_VALUE, {not_selected} }
                 {items}
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn app() -> Element {
+        let mut value = use_signal(|| SelectEnumValueOpt::new(Some(TimeOffset::Utc)));
+        let select = select_enum_value_opt(
+            "Time offset",
+            &*value.read(),
+            EventHandler::new(move |offset| value.write().set_value(offset)),
+        );
+
+        let selected = match value.read().get_value() {
+            Some(value) => value.as_str(),
+            None => "none",
+        };
+
+        rsx! {
+            {select}
+            div { "value: {selected}" }
+        }
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("select_enum_value_opt", &harness.render_html());
+    }
+
+    #[test]
+    fn emits_none_for_not_selected() {
+        let mut harness = ComponentHarness::new(app);
+        harness.fire_change(NULL_VALUE);
+        assert!(harness.render_html().contains("value: none"));
+    }
+
+    fn app_with_id() -> Element {
+        select_enum_value_opt_with_id(
+            "Time offset",
+            "live-offset",
+            &SelectEnumValueOpt::new(None::<TimeOffset>).allow_null_result(false),
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("for=\"live-offset\""));
+        assert!(html.contains("id=\"live-offset\" aria-required=\"true\""));
+    }
+
+    struct TestCatalog;
+
+    impl MessageCatalog for TestCatalog {
+        fn get_text(&self, key: &MessageKey) -> Option<String> {
+            match key {
+                MessageKey::NotSelected => Some("Nicht ausgewählt".to_string()),
+                _ => None,
+            }
+        }
+    }
+
+    fn translated_app() -> Element {
+        use_translator_provider(|| Translator::new(TestCatalog));
+        app()
+    }
+
+    #[test]
+    fn renders_translated_strings() {
+        let harness = ComponentHarness::new(translated_app);
+        assert!(harness.render_html().contains("Nicht ausgewählt"));
+    }
+}
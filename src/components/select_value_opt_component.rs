@@ -0,0 +1,184 @@
+use super::{ControlIds, NULL_VALUE};
+use crate::i18n::*;
+use crate::theme::Theme;
+use crate::types::*;
+use dioxus::prelude::*;
+
+/// Native `select` of a [`SelectedValueOpt`]. Each named group is rendered as an `optgroup`.
+pub fn select_value_opt<TValue: SelectValue + Clone + 'static>(
+    caption: &str,
+    value_selector: &SelectedValueOpt<TValue>,
+    on_input: EventHandler<Option<TValue>>,
+) -> Element {
+    let ids = ControlIds::new(caption, None);
+    select_value_opt_with_id(caption, &ids.control, value_selector, on_input)
+}
+
+/// [`select_value_opt`] with an explicit id, for selects sharing a caption on one page.
+pub fn select_value_opt_with_id<TValue: SelectValue + Clone + 'static>(
+    caption: &str,
+    id: &str,
+    value_selector: &SelectedValueOpt<TValue>,
+    on_input: EventHandler<Option<TValue>>,
+) -> Element {
+    let selected_id = value_selector
+        .get_selected()
+        .map(|selected| selected.get_id());
+    let translator = Translator::current();
+    let theme = Theme::current();
+    let ids = ControlIds::new(caption, Some(id));
+
+    let groups = value_selector.get_items().iter().map(|group| {
+        let items = group.values.iter().map(|itm| {
+            let selected = Some(itm.get_id()) == selected_id;
+            rsx! {
+                option { selected, value: itm.get_id(), {itm.get_value()} }
+            }
+        });
+
+        if group.name.is_empty() {
+            rsx! {
+                {items}
+            }
+        } else {
+            rsx! {
+                optgroup { label: group.name, {items} }
+            }
+        }
+    });
+
+    // A required value shows a disabled "Not selected" until something is picked.
+    let required = value_selector.is_required();
+    let not_selected = if required && selected_id.is_some() {
+        rsx! {}
+    } else {
+        let not_selected = translator.translate(&MessageKey::NotSelected);
+        rsx! {
+            option { value: NULL_VALUE, disabled: required, {not_selected} }
+        }
+    };
+
+    // Only the picked item is cloned, once the user has changed the selection.
+    let items = value_selector.get_shared_items();
+
+    rsx! {
+        div { class: theme.wrapper,
+            label { r#for: ids.control.as_str(), {caption} }
+            select {
+                id: ids.control.as_str(),
+                aria_required: required,
+                class: theme.select,
+                onchange: move |e| {
+                    let value = e.value();
+                    let value = items
+                        .iter()
+                        .flat_map(|group| group.values.iter())
+                        .find(|itm| itm.get_id() == value.as_str())
+                        .cloned();
+                    on_input.call(value);
+                },
+                value: selected_id.unwrap_or(NULL_VALUE),
+                {not_selected}
+                {groups}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+    use crate::test_utils::*;
+
+    fn create_value() -> SelectedValueOpt<Account> {
+        let mut result = SelectedValueOpt::new(vec![SelectItemsGroup::new(
+            "Live",
+            vec![
+                Account::new("L1").with_name("Live USD"),
+                Account::new("L2").with_name("Live EUR"),
+            ],
+        )]);
+
+        result.push(vec![Account::new("D1").with_name("Demo")]);
+        result
+    }
+
+    fn app() -> Element {
+        let mut value = use_signal(create_value);
+
+        let select = select_value_opt(
+            "Account",
+            &*value.read(),
+            EventHandler::new(move |account: Option<Account>| {
+                value
+                    .write()
+                    .set_selected(account.as_ref().map(|account| account.id.as_str()))
+            }),
+        );
+
+        let selected = match value.read().get_selected() {
+            Some(selected) => selected.name.clone(),
+            None => "none".to_string(),
+        };
+        render_with_value(select, selected)
+    }
+
+    #[test]
+    fn renders_groups_and_emits_selected_value() {
+        let mut harness = ComponentHarness::new(app);
+
+        let html = harness.render_html();
+        assert!(html.contains("<optgroup label=\"Live\">"));
+        assert_eq!(html.matches("<optgroup").count(), 1);
+        assert!(html.contains(">Not selected</option>"));
+
+        harness.fire_change("L2");
+        assert!(harness.render_html().contains("value: Live EUR"));
+
+        harness.fire_change(NULL_VALUE);
+        assert!(harness.render_html().contains("value: none"));
+    }
+
+    fn required_app() -> Element {
+        let mut value = use_signal(|| create_value().allow_null_result(false));
+
+        select_value_opt(
+            "Account",
+            &*value.read(),
+            EventHandler::new(move |account: Option<Account>| {
+                value
+                    .write()
+                    .set_selected(account.as_ref().map(|account| account.id.as_str()))
+            }),
+        )
+    }
+
+    #[test]
+    fn offers_not_selected_for_required_value_until_picked() {
+        let mut harness = ComponentHarness::new(required_app);
+
+        let html = harness.render_html();
+        assert!(html.contains("aria-required=\"true\""));
+        assert!(html.contains("disabled=\"true\">Not selected</option>"));
+
+        harness.fire_change("D1");
+        assert!(!harness.render_html().contains("Not selected"));
+    }
+
+    fn app_with_id() -> Element {
+        select_value_opt_with_id(
+            "Account",
+            "live-account",
+            &create_value(),
+            EventHandler::new(|_| {}),
+        )
+    }
+
+    #[test]
+    fn renders_explicit_id() {
+        let html = ComponentHarness::new(app_with_id).render_html();
+        assert!(html.contains("for=\"live-account\""));
+        assert!(html.contains("id=\"live-account\""));
+    }
+}
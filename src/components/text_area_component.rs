@@ -0,0 +1,276 @@
+use super::{ControlIds, get_validation_state, render_required_mark};
+use crate::theme::Theme;
+use crate::types::{DetailedValidator, ValidationError, ValidationMessageFormatter};
+use dioxus::prelude::*;
+use rust_extensions::AsStr;
+use std::{marker::PhantomData, rc::Rc};
+
+const DEFAULT_ROWS: usize = 3;
+
+const AUTO_GROW_SCRIPT: &str = r#"const id = await dioxus.recv();
+const el = document.getElementById(id);
+if (el) {
+    el.style.height = "auto";
+    el.style.height = el.scrollHeight + "px";
+}"#;
+
+/// Multiline counterpart of `InputValueComponent`.
+pub struct TextAreaComponent<'s, TValue: 'static + AsStr + DetailedValidator> {
+    caption: &'s str,
+    value: Option<&'s TValue>,
+    read_only: bool,
+    required: bool,
+    id: Option<&'s str>,
+    rows: usize,
+    auto_grow: bool,
+    max_length: Option<usize>,
+    placeholder: Option<&'s str>,
+    on_input: Option<EventHandler<String>>,
+    on_press_ctrl_enter: Option<EventHandler<()>>,
+    message_formatter: Option<ValidationMessageFormatter>,
+    ph: PhantomData<TValue>,
+}
+
+impl<'s, TValue: 'static + AsStr + DetailedValidator> TextAreaComponent<'s, TValue> {
+    pub fn new(caption: &'s str) -> Self {
+        Self {
+            caption,
+            value: None,
+            read_only: false,
+            required: false,
+            id: None,
+            rows: DEFAULT_ROWS,
+            auto_grow: false,
+            max_length: None,
+            placeholder: None,
+            on_input: None,
+            on_press_ctrl_enter: None,
+            message_formatter: None,
+            ph: Default::default(),
+        }
+    }
+
+    pub fn set_value(mut self, value: &'s TValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Shows an asterisk after the caption and reports a blank value once it is touched.
+    pub fn set_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn set_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn set_rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Grows the textarea with the text instead of scrolling. `rows` is the minimal height.
+    pub fn set_auto_grow(mut self, auto_grow: bool) -> Self {
+        self.auto_grow = auto_grow;
+        self
+    }
+
+    /// Limits the text and shows the `typed / max` counter under the textarea.
+    pub fn set_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn set_placeholder(mut self, placeholder: &'s str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn set_id(mut self, id: &'s str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn on_input(mut self, on_input: impl FnMut(String) + 'static) -> Self {
+        self.on_input = Some(EventHandler::new(on_input));
+        self
+    }
+
+    /// Enter inserts a new line in a textarea, so Ctrl+Enter (Cmd+Enter on macOS) submits.
+    pub fn on_press_ctrl_enter(mut self, on_press: impl Into<EventHandler<()>>) -> Self {
+        self.on_press_ctrl_enter = Some(on_press.into());
+        self
+    }
+
+    pub fn set_message_formatter(
+        mut self,
+        formatter: impl Fn(&ValidationError) -> Option<String> + 'static,
+    ) -> Self {
+        self.message_formatter = Some(Rc::new(formatter));
+        self
+    }
+
+    pub fn render(self) -> Element {
+        let theme = Theme::current();
+
+        let mut style = if self.read_only {
+            theme.input_read_only_style.to_string()
+        } else {
+            String::new()
+        };
+
+        if self.auto_grow {
+            style.push_str("resize: none;overflow-y: hidden;");
+        }
+
+        let ids = ControlIds::new(self.caption, self.id);
+        let validation = get_validation_state(
+            self.value,
+            self.required,
+            self.message_formatter.as_ref(),
+            &ids,
+        );
+        if validation.aria_invalid && !theme.input_error_style.is_empty() {
+            style.push_str(theme.input_error_style);
+            style.push(';');
+        }
+
+        let required = self.required || self.value.is_some_and(|value| value.is_required());
+
+        let value_as_str = self.value.map(|value| value.as_str()).unwrap_or_default();
+
+        let counter = match self.max_length {
+            Some(max_length) => {
+                let length = value_as_str.chars().count();
+                let class = if length > max_length {
+                    theme.char_counter_exceeded
+                } else {
+                    theme.char_counter
+                };
+                rsx! {
+                    div { class, aria_live: "polite", "{length} / {max_length}" }
+                }
+            }
+            None => rsx! {},
+        };
+
+        let on_input = self.on_input;
+        let on_press_ctrl_enter = self.on_press_ctrl_enter;
+        let auto_grow = self.auto_grow;
+        let control_id = ids.control.clone();
+        let mounted_control_id = ids.control.clone();
+
+        rsx! {
+            div { class: "{theme.wrapper} {validation.error_class}",
+                label { r#for: ids.control.as_str(),
+                    {self.caption}
+                    {render_required_mark(required)}
+                }
+                {validation.message}
+
+                textarea {
+                    class: theme.input,
+                    id: ids.control.as_str(),
+                    style: "{style}",
+                    rows: self.rows,
+                    maxlength: self.max_length,
+                    placeholder: self.placeholder,
+                    readonly: self.read_only,
+                    aria_required: required,
+                    aria_invalid: validation.aria_invalid,
+                    aria_describedby: validation.aria_describedby,
+                    value: value_as_str,
+                    oninput: move |e| {
+                        if let Some(on_input) = on_input {
+                            on_input.call(e.value());
+                        }
+
+                        if auto_grow {
+                            grow_to_content(control_id.clone());
+                        }
+                    },
+                    onmounted: move |_| {
+                        if auto_grow {
+                            grow_to_content(mounted_control_id.clone());
+                        }
+                    },
+                    onkeydown: move |e| {
+                        let modifiers = e.modifiers();
+                        if e.key() == Key::Enter && (modifiers.ctrl() || modifiers.meta())
+                            && let Some(on_press_ctrl_enter) = on_press_ctrl_enter.as_ref()
+                        {
+                            e.prevent_default();
+                            on_press_ctrl_enter.call(());
+                        }
+                    },
+                }
+                {counter}
+            }
+        }
+    }
+}
+
+/// Fits the height of the textarea to its content, e.g. a value loaded with the form.
+fn grow_to_content(id: String) {
+    let eval = document::eval(AUTO_GROW_SCRIPT);
+    let _ = eval.send(id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::types::{InputValue, LengthRule};
+
+    fn app() -> Element {
+        let mut value =
+            use_signal(|| InputValue::new("Hello".to_string()).with_rule(LengthRule::max(10)));
+        let mut submitted = use_signal(|| 0);
+
+        let text_area = TextAreaComponent::new("Comment")
+            .set_value(&*value.read())
+            .set_rows(5)
+            .set_max_length(10)
+            .on_input(move |v| value.write().set_string_value(v))
+            .on_press_ctrl_enter(EventHandler::new(move |_| submitted += 1))
+            .render();
+
+        rsx! {
+            {text_area}
+            div { "submitted: {submitted}" }
+        }
+    }
+
+    #[test]
+    fn renders_snapshot() {
+        let harness = ComponentHarness::new(app);
+        assert_snapshot("text_area_component", &harness.render_html());
+    }
+
+    #[test]
+    fn counts_characters_and_validates() {
+        let mut harness = ComponentHarness::new(app);
+        assert!(harness.render_html().contains("5 / 10"));
+
+        harness.fire_input("Hello, world");
+        let html = harness.render_html();
+        assert!(html.contains("12 / 10"));
+        assert!(html.contains("text-danger"));
+        assert!(html.contains("edit-validation-err-message"));
+        assert!(html.contains("id=\"edit-comment-error\" role=\"alert\""));
+        assert!(html.contains("aria-invalid=\"true\" aria-describedby=\"edit-comment-error\""));
+    }
+
+    #[test]
+    fn submits_on_ctrl_enter_only() {
+        let mut harness = ComponentHarness::new(app);
+
+        harness.fire_key_down_at(0, Key::Enter, Modifiers::empty());
+        assert!(harness.render_html().contains("submitted: 0"));
+
+        harness.fire_key_down_at(0, Key::Enter, Modifiers::CONTROL);
+        assert!(harness.render_html().contains("submitted: 1"));
+    }
+}
@@ -0,0 +1,89 @@
+use super::ControlIds;
+use crate::theme::Theme;
+use crate::types::{DetailedValidator, ValidationErrorKind, ValidationMessageFormatter};
+use dioxus::prelude::*;
+
+/// Message of the current validation error. An empty value is reported only for
+/// a required field which is touched: until then the user has not typed anything yet.
+/// `required` is the flag of the component, which makes an optional value required on screen.
+/// A pending async check is shown by the spinner rather than a message.
+pub(crate) fn get_validation_message(
+    value: &(impl DetailedValidator + ?Sized),
+    required: bool,
+    formatter: Option<&ValidationMessageFormatter>,
+) -> Option<String> {
+    let err = value.get_validation_error()?;
+
+    if err.get_kind() == ValidationErrorKind::Pending {
+        return None;
+    }
+
+    if err.get_kind() == ValidationErrorKind::Empty
+        && !((required || value.is_required()) && value.is_touched())
+    {
+        return None;
+    }
+
+    Some(err.format_message(formatter))
+}
+
+/// Validation message of a control and the attributes which refer to it.
+pub(crate) struct ValidationState {
+    pub aria_invalid: bool,
+    pub aria_describedby: Option<String>,
+    /// `wrapper_error` of the theme while the value is invalid, empty otherwise.
+    pub error_class: &'static str,
+    pub message: Element,
+}
+
+/// See [`get_validation_message`] for `required` and when a message is shown.
+pub(crate) fn get_validation_state(
+    value: Option<&(impl DetailedValidator + ?Sized)>,
+    required: bool,
+    formatter: Option<&ValidationMessageFormatter>,
+    ids: &ControlIds,
+) -> ValidationState {
+    let message = value.and_then(|value| get_validation_message(value, required, formatter));
+    let aria_invalid = message.is_some();
+
+    ValidationState {
+        aria_invalid,
+        aria_describedby: ids.get_described_by(aria_invalid, false),
+        error_class: if aria_invalid {
+            Theme::current().wrapper_error
+        } else {
+            ""
+        },
+        message: render_validation_message(message.as_deref(), &ids.get_error_id()),
+    }
+}
+
+/// Asterisk after the caption of a required field. Screen readers get `aria-required` instead.
+pub(crate) fn render_required_mark(required: bool) -> Element {
+    if !required {
+        return rsx! {};
+    }
+
+    rsx! {
+        span {
+            class: Theme::current().required_mark,
+            aria_hidden: "true",
+            " *"
+        }
+    }
+}
+
+/// `id` is what the control refers to in `aria-describedby`.
+pub(crate) fn render_validation_message(message: Option<&str>, id: &str) -> Element {
+    match message {
+        Some(message) => rsx! {
+            div {
+                class: Theme::current().validation_message,
+                id,
+                role: "alert",
+                {message}
+            }
+        },
+        None => rsx! {},
+    }
+}
@@ -0,0 +1,33 @@
+use super::MessageKey;
+
+/// Source of translated kit strings. Returning `None` falls back to [`EnglishCatalog`].
+pub trait MessageCatalog {
+    fn get_text(&self, key: &MessageKey) -> Option<String>;
+}
+
+pub struct EnglishCatalog;
+
+impl MessageCatalog for EnglishCatalog {
+    fn get_text(&self, key: &MessageKey) -> Option<String> {
+        match key {
+            MessageKey::NotSelected => Some("Not selected".to_string()),
+            MessageKey::RowActions => Some("Row actions".to_string()),
+            MessageKey::Increase => Some("Increase".to_string()),
+            MessageKey::Decrease => Some("Decrease".to_string()),
+            MessageKey::ShowPassword => Some("Show password".to_string()),
+            MessageKey::Clear => Some("Clear".to_string()),
+            MessageKey::NoMatches => Some("No matches".to_string()),
+            MessageKey::MoreItems(amount) => {
+                Some(format!("{} more, keep typing to narrow down", amount))
+            }
+            MessageKey::AddItem => Some("Add…".to_string()),
+            MessageKey::RemoveItem(label) => Some(format!("Remove {}", label)),
+            MessageKey::Available => Some("Available".to_string()),
+            MessageKey::Selected => Some("Selected".to_string()),
+            MessageKey::AddSelected => Some("Add".to_string()),
+            MessageKey::RemoveSelected => Some("Remove".to_string()),
+            MessageKey::Validation(err) => Some(err.get_message()),
+            MessageKey::EnumValue { value, .. } => Some(value.to_string()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::types::{EnumIterator, ValidationError};
+
+pub enum MessageKey<'s> {
+    NotSelected,
+    RowActions,
+    Increase,
+    Decrease,
+    ShowPassword,
+    Clear,
+    NoMatches,
+    MoreItems(usize),
+    AddItem,
+    RemoveItem(&'s str),
+    Available,
+    Selected,
+    AddSelected,
+    RemoveSelected,
+    Validation(&'s ValidationError),
+    EnumValue {
+        enum_name: &'static str,
+        value: &'s str,
+    },
+}
+
+impl<'s> MessageKey<'s> {
+    pub fn enum_value<TItem: EnumIterator>(value: &'s str) -> Self {
+        Self::EnumValue {
+            enum_name: TItem::NAME,
+            value,
+        }
+    }
+}
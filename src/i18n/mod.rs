@@ -0,0 +1,6 @@
+mod message_key;
+pub use message_key::*;
+mod message_catalog;
+pub use message_catalog::*;
+mod translator;
+pub use translator::*;
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use dioxus::{core::Runtime, prelude::*};
+
+use super::*;
+
+/// Translation provider shared through the Dioxus context.
+///
+/// Kit components look it up with [`Translator::current`] and use the English bundle
+/// when no provider is registered.
+#[derive(Clone)]
+pub struct Translator {
+    catalog: Rc<dyn MessageCatalog>,
+}
+
+impl Default for Translator {
+    fn default() -> Self {
+        Self::new(EnglishCatalog)
+    }
+}
+
+impl Translator {
+    pub fn new(catalog: impl MessageCatalog + 'static) -> Self {
+        Self {
+            catalog: Rc::new(catalog),
+        }
+    }
+
+    pub fn current() -> Self {
+        let in_scope = Runtime::try_current()
+            .and_then(|runtime| runtime.try_current_scope_id())
+            .is_some();
+
+        if !in_scope {
+            return Self::default();
+        }
+
+        try_consume_context::<Self>().unwrap_or_default()
+    }
+
+    pub fn translate(&self, key: &MessageKey) -> String {
+        if let MessageKey::Validation(err) = key
+            && let Some(message) = err.get_custom_message()
+        {
+            return message.to_string();
+        }
+
+        if let Some(result) = self.catalog.get_text(key) {
+            return result;
+        }
+
+        EnglishCatalog.get_text(key).unwrap_or_default()
+    }
+}
+
+pub fn use_translator_provider(catalog: impl FnOnce() -> Translator) -> Translator {
+    use_context_provider(catalog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidationError;
+
+    struct GermanCatalog;
+
+    impl MessageCatalog for GermanCatalog {
+        fn get_text(&self, key: &MessageKey) -> Option<String> {
+            match key {
+                MessageKey::NotSelected => Some("Nicht ausgewählt".to_string()),
+                MessageKey::EnumValue {
+                    enum_name: "TimeOffset",
+                    value: "+00:00",
+                } => Some("UTC".to_string()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn falls_back_to_english() {
+        let translator = Translator::new(GermanCatalog);
+
+        assert_eq!(
+            translator.translate(&MessageKey::NotSelected),
+            "Nicht ausgewählt"
+        );
+        assert_eq!(translator.translate(&MessageKey::RowActions), "Row actions");
+
+        let err = ValidationError::min_value(3);
+        assert_eq!(
+            translator.translate(&MessageKey::Validation(&err)),
+            "must be ≥ 3"
+        );
+
+        let err = ValidationError::custom("reserved name");
+        assert_eq!(
+            translator.translate(&MessageKey::Validation(&err)),
+            "reserved name"
+        );
+    }
+
+    #[test]
+    fn translates_enum_values() {
+        let translator = Translator::new(GermanCatalog);
+
+        let key = MessageKey::enum_value::<crate::types::TimeOffset>("+00:00");
+        assert_eq!(translator.translate(&key), "UTC");
+
+        let key = MessageKey::enum_value::<crate::types::TimeOffset>("+03:00");
+        assert_eq!(translator.translate(&key), "+03:00");
+    }
+}
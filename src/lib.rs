@@ -1,2 +1,18 @@
+extern crate self as dioxus_admin_ui_kit;
+
 pub mod components;
+pub mod i18n;
+#[cfg(test)]
+mod test_fixtures;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod theme;
 pub mod types;
+
+pub use dioxus_admin_ui_kit_macros::AdminForm;
+
+#[doc(hidden)]
+pub mod macro_support {
+    pub use dioxus;
+    pub use rust_common::validators::ValueValidationResult;
+}
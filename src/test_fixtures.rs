@@ -0,0 +1,59 @@
+//! Fixtures shared by the tests of the select components and values.
+
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::types::{SelectItemsGroup, SelectValue};
+
+/// Item of the select components under test.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Account {
+    pub(crate) id: String,
+    pub(crate) name: String,
+}
+
+impl Account {
+    /// Account labelled with its id.
+    pub(crate) fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+        }
+    }
+
+    pub(crate) fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+impl SelectValue for Account {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    fn get_value(&self) -> &str {
+        &self.name
+    }
+}
+
+pub(crate) fn create_accounts(ids: &[&str]) -> Vec<Account> {
+    ids.iter().map(|id| Account::new(id)).collect()
+}
+
+/// `L1` and `L2` in the "Live" group, `D1` in the "Demo" one.
+pub(crate) fn create_account_groups() -> Vec<SelectItemsGroup<Account>> {
+    vec![
+        SelectItemsGroup::new("Live", create_accounts(&["L1", "L2"])),
+        SelectItemsGroup::new("Demo", create_accounts(&["D1"])),
+    ]
+}
+
+/// Renders `control` followed by `value: {value}`, so a test can check what was emitted.
+pub(crate) fn render_with_value(control: Element, value: impl Display) -> Element {
+    rsx! {
+        {control}
+        div { "value: {value}" }
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    any::Any,
+    future::Future,
+    rc::Rc,
+    task::{Context, Waker},
+    time::Duration,
+};
+
+use super::ManualClock;
+use crate::types::Clock;
+use dioxus::core::{ElementId, Event, Mutation, Mutations};
+use dioxus::prelude::*;
+use dioxus_html::{
+    Code, Location, Modifiers, PlatformEventData, SerializedFocusData, SerializedFormData,
+    SerializedHtmlEventConverter, SerializedKeyboardData, SerializedMouseData, SerializedWheelData,
+    point_interaction::SerializedPointInteraction,
+};
+
+pub struct ComponentHarness {
+    dom: VirtualDom,
+    listeners: Vec<(String, ElementId)>,
+    clock: ManualClock,
+}
+
+impl ComponentHarness {
+    pub fn new(app: fn() -> Element) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let clock = ManualClock::new();
+
+        let mut dom = VirtualDom::new(app).with_root_context(Clock::new(clock.clone()));
+        let mutations = dom.rebuild_to_vec();
+
+        let mut result = Self {
+            dom,
+            listeners: Vec::new(),
+            clock,
+        };
+
+        result.apply_mutations(mutations);
+        result
+    }
+
+    pub fn render_html(&self) -> String {
+        dioxus_ssr::render(&self.dom)
+    }
+
+    pub fn has_listener(&self, event_name: &str) -> bool {
+        self.listeners.iter().any(|(name, _)| name == event_name)
+    }
+
+    pub fn fire_input(&mut self, value: &str) {
+        self.fire_input_at(0, value);
+    }
+
+    pub fn fire_input_at(&mut self, index: usize, value: &str) {
+        let data = SerializedFormData::new(value.to_string(), Vec::new());
+        self.fire_event_at("input", index, Box::new(data));
+    }
+
+    pub fn fire_checked(&mut self, checked: bool) {
+        self.fire_input(if checked { "true" } else { "false" });
+    }
+
+    pub fn fire_change(&mut self, value: &str) {
+        self.fire_change_at(0, value);
+    }
+
+    pub fn fire_change_at(&mut self, index: usize, value: &str) {
+        let data = SerializedFormData::new(value.to_string(), Vec::new());
+        self.fire_event_at("change", index, Box::new(data));
+    }
+
+    pub fn fire_key_up(&mut self, key: Key) {
+        self.fire_key_up_at(0, key);
+    }
+
+    pub fn fire_key_up_at(&mut self, index: usize, key: Key) {
+        let data = SerializedKeyboardData::new(
+            key,
+            Code::Unidentified,
+            Location::Standard,
+            false,
+            Modifiers::empty(),
+            false,
+        );
+        self.fire_event_at("keyup", index, Box::new(data));
+    }
+
+    pub fn fire_key_down_at(&mut self, index: usize, key: Key, modifiers: Modifiers) {
+        let data = SerializedKeyboardData::new(
+            key,
+            Code::Unidentified,
+            Location::Standard,
+            false,
+            modifiers,
+            false,
+        );
+        self.fire_event_at("keydown", index, Box::new(data));
+    }
+
+    pub fn fire_click_at(&mut self, index: usize) {
+        self.fire_event_at("click", index, Box::new(SerializedMouseData::default()));
+    }
+
+    pub fn fire_focus_at(&mut self, index: usize) {
+        self.fire_event_at("focus", index, Box::new(SerializedFocusData::default()));
+    }
+
+    pub fn fire_blur_at(&mut self, index: usize) {
+        self.fire_event_at("blur", index, Box::new(SerializedFocusData::default()));
+    }
+
+    /// Negative `delta_y` scrolls up.
+    pub fn fire_wheel_at(&mut self, index: usize, delta_y: f64) {
+        let data = SerializedWheelData {
+            mouse: SerializedPointInteraction::default(),
+            delta_mode: 0,
+            delta_x: 0.0,
+            delta_y,
+            delta_z: 0.0,
+        };
+        self.fire_event_at("wheel", index, Box::new(data));
+    }
+
+    /// Fires `event_name` at the `index`-th element which listens to it, in render order.
+    pub fn fire_event_at(&mut self, event_name: &str, index: usize, data: Box<dyn Any>) {
+        let element_id = self
+            .listeners
+            .iter()
+            .filter(|(name, _)| name == event_name)
+            .map(|(_, id)| *id)
+            .nth(index)
+            .unwrap_or_else(|| panic!("No element #{} listens to '{}'", index, event_name));
+
+        let data: Rc<dyn Any> = Rc::new(PlatformEventData::new(data));
+        let event = Event::new(data, true);
+        self.dom
+            .runtime()
+            .handle_event(event_name, event, element_id);
+
+        self.dom.process_events();
+        let mutations = self.dom.render_immediate_to_vec();
+        self.apply_mutations(mutations);
+    }
+
+    /// Polls spawned tasks until none of them is ready to make progress and re-renders.
+    /// Pending timers are fired in order, as if their time had passed.
+    pub fn run_tasks(&mut self) {
+        loop {
+            self.poll_tasks();
+
+            if !self.clock.advance_to_next_timer() {
+                break;
+            }
+        }
+    }
+
+    /// Moves the [`ManualClock`] forward by `duration` and polls the tasks whose timers fired.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+        self.poll_tasks();
+    }
+
+    fn poll_tasks(&mut self) {
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            let ready = {
+                let mut work = std::pin::pin!(self.dom.wait_for_work());
+                work.as_mut().poll(&mut context).is_ready()
+            };
+
+            if !ready {
+                break;
+            }
+
+            let mutations = self.dom.render_immediate_to_vec();
+            self.apply_mutations(mutations);
+        }
+    }
+
+    fn apply_mutations(&mut self, mutations: Mutations) {
+        for mutation in mutations.edits {
+            match mutation {
+                Mutation::NewEventListener { name, id } => {
+                    self.listeners.push((name, id));
+                }
+                Mutation::RemoveEventListener { name, id } => {
+                    self.listeners
+                        .retain(|(itm_name, itm_id)| !(itm_name == &name && itm_id == &id));
+                }
+                Mutation::Remove { id } | Mutation::ReplaceWith { id, .. } => {
+                    self.listeners.retain(|(_, itm_id)| itm_id != &id);
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::poll_fn,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use crate::types::{AsyncValueValidator, ValidationError, ValidationFuture};
+
+/// In-process [`AsyncValueValidator`]: values registered with [`FakeAsyncValidator::reject`]
+/// fail, all others pass. Answers immediately unless [`FakeAsyncValidator::hold`] is set,
+/// in which case checks stay pending until [`FakeAsyncValidator::release`].
+/// Clones share the same state, so a test can keep one to drive and inspect the checks.
+#[derive(Clone, Default)]
+pub struct FakeAsyncValidator {
+    rejected: Rc<RefCell<HashMap<String, ValidationError>>>,
+    checked: Rc<RefCell<Vec<String>>>,
+    held: Rc<Cell<bool>>,
+    waiting: Rc<RefCell<Vec<Waker>>>,
+}
+
+impl FakeAsyncValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reject(self, value: &str, err: ValidationError) -> Self {
+        self.rejected.borrow_mut().insert(value.to_string(), err);
+        self
+    }
+
+    pub fn hold(self) -> Self {
+        self.held.set(true);
+        self
+    }
+
+    pub fn release(&self) {
+        self.held.set(false);
+        for waker in self.waiting.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub fn get_checked_values(&self) -> Vec<String> {
+        self.checked.borrow().clone()
+    }
+}
+
+impl AsyncValueValidator for FakeAsyncValidator {
+    fn validate(&self, value: String) -> ValidationFuture {
+        self.checked.borrow_mut().push(value.clone());
+
+        let result = match self.rejected.borrow().get(value.as_str()) {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        };
+
+        let held = self.held.clone();
+        let waiting = self.waiting.clone();
+
+        Box::pin(poll_fn(move |cx| {
+            if held.get() {
+                waiting.borrow_mut().push(cx.waker().clone());
+                return Poll::Pending;
+            }
+
+            Poll::Ready(result.clone())
+        }))
+    }
+}
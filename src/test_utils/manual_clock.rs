@@ -0,0 +1,104 @@
+use std::{
+    cell::RefCell,
+    future::{Future, poll_fn},
+    pin::Pin,
+    rc::Rc,
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use crate::types::Timer;
+
+/// Paused clock for the debounce timers of the kit. Provided as the [`Clock`](crate::types::Clock)
+/// of a component, its timers only fire when the clock is advanced, so a test decides exactly
+/// how much time passes between two events. [`ComponentHarness`](super::ComponentHarness)
+/// provides one to the component under test.
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    state: Rc<RefCell<ClockState>>,
+}
+
+#[derive(Default)]
+struct ClockState {
+    now: Duration,
+    timers: Vec<(Duration, Rc<RefCell<Waker>>)>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_elapsed(&self) -> Duration {
+        self.state.borrow().now
+    }
+
+    /// Moves the clock forward and wakes every timer which is due.
+    pub fn advance(&self, duration: Duration) {
+        let now = self.state.borrow().now + duration;
+        self.advance_to(now);
+    }
+
+    /// Jumps to the earliest pending timer. Returns `false` if there is none.
+    pub fn advance_to_next_timer(&self) -> bool {
+        let next = {
+            let state = self.state.borrow();
+            state.timers.iter().map(|(deadline, _)| *deadline).min()
+        };
+
+        match next {
+            Some(next) => {
+                self.advance_to(next.max(self.get_elapsed()));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn advance_to(&self, now: Duration) {
+        let due = {
+            let mut state = self.state.borrow_mut();
+            state.now = now;
+
+            let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut state.timers)
+                .into_iter()
+                .partition(|(deadline, _)| *deadline <= now);
+            state.timers = pending;
+            due
+        };
+
+        for (_, waker) in due {
+            waker.borrow().wake_by_ref();
+        }
+    }
+}
+
+impl Timer for ManualClock {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()>>> {
+        let state = self.state.clone();
+        let deadline = self.get_elapsed() + duration;
+        let mut registered: Option<Rc<RefCell<Waker>>> = None;
+
+        Box::pin(poll_fn(move |cx| {
+            let mut state = state.borrow_mut();
+            if state.now >= deadline {
+                return Poll::Ready(());
+            }
+
+            match registered.as_ref() {
+                Some(waker) => {
+                    if !waker.borrow().will_wake(cx.waker()) {
+                        *waker.borrow_mut() = cx.waker().clone();
+                    }
+                }
+                None => {
+                    let waker = Rc::new(RefCell::new(cx.waker().clone()));
+                    state.timers.push((deadline, waker.clone()));
+                    registered = Some(waker);
+                }
+            }
+
+            Poll::Pending
+        }))
+    }
+}
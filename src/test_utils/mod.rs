@@ -0,0 +1,21 @@
+//! Helpers to test kit components without a browser.
+//!
+//! Components are rendered to HTML through Dioxus SSR and compared against snapshots stored in
+//! `snapshots/` of the crate under test. A missing snapshot fails the test; set
+//! `UPDATE_SNAPSHOTS=1` to create it or to overwrite the stored ones after an intended
+//! markup change.
+//!
+//! [`ComponentHarness`] keeps a headless `VirtualDom` alive so events (`input`, `change`,
+//! `keyup`) can be fired at the rendered elements and the re-rendered HTML inspected.
+//! [`ComponentHarness::run_tasks`] drives spawned futures, e.g. async validation backed by
+//! [`FakeAsyncValidator`], and advances the [`ManualClock`] through pending debounce timers;
+//! [`ComponentHarness::advance_time`] lets a test stop inside a debounce window instead.
+
+mod snapshot;
+pub use snapshot::*;
+mod component_harness;
+pub use component_harness::*;
+mod fake_async_validator;
+pub use fake_async_validator::*;
+mod manual_clock;
+pub use manual_clock::*;
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+const SNAPSHOTS_FOLDER: &str = "snapshots";
+const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+pub fn assert_snapshot(name: &str, html: &str) {
+    let path = get_snapshot_path(name);
+
+    let update = std::env::var(UPDATE_SNAPSHOTS_ENV)
+        .map(|value| value == "1")
+        .unwrap_or(false);
+
+    if update {
+        if let Some(folder) = path.parent() {
+            std::fs::create_dir_all(folder).unwrap();
+        }
+        std::fs::write(&path, html).unwrap();
+        return;
+    }
+
+    if !path.exists() {
+        panic!(
+            "Snapshot '{}' is missing. Run with {}=1 to create it",
+            path.display(),
+            UPDATE_SNAPSHOTS_ENV
+        );
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+
+    assert_eq!(
+        expected.trim_end(),
+        html.trim_end(),
+        "Snapshot '{}' does not match. Run with {}=1 to accept the new output",
+        path.display(),
+        UPDATE_SNAPSHOTS_ENV
+    );
+}
+
+fn get_snapshot_path(name: &str) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+
+    let mut result = PathBuf::from(root);
+    result.push(SNAPSHOTS_FOLDER);
+    result.push(format!("{}.html", name));
+    result
+}
@@ -0,0 +1,67 @@
+use dioxus::prelude::*;
+
+const COLOR_SCHEME_ATTRIBUTE: &str = "data-kit-color-scheme";
+
+/// Variant of the kit stylesheet. [`ColorScheme::System`] follows `prefers-color-scheme`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorScheme {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    fn get_script(&self) -> String {
+        match self {
+            Self::System => format!(
+                "document.documentElement.removeAttribute({:?});",
+                COLOR_SCHEME_ATTRIBUTE
+            ),
+            _ => format!(
+                "document.documentElement.setAttribute({:?}, {:?});",
+                COLOR_SCHEME_ATTRIBUTE,
+                self.as_str()
+            ),
+        }
+    }
+}
+
+/// Switches the kit stylesheet to `scheme` by setting `data-kit-color-scheme` on `<html>`.
+pub fn set_color_scheme(scheme: ColorScheme) {
+    document::eval(&scheme.get_script());
+}
+
+/// Keeps the document in sync with the returned signal: writing to it switches the scheme.
+pub fn use_color_scheme(init: ColorScheme) -> Signal<ColorScheme> {
+    let scheme = use_signal(|| init);
+
+    use_effect(move || set_color_scheme(scheme()));
+
+    scheme
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pins_scheme_on_document_element() {
+        assert_eq!(
+            ColorScheme::Dark.get_script(),
+            "document.documentElement.setAttribute(\"data-kit-color-scheme\", \"dark\");"
+        );
+        assert_eq!(
+            ColorScheme::System.get_script(),
+            "document.documentElement.removeAttribute(\"data-kit-color-scheme\");"
+        );
+    }
+}
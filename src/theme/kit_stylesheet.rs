@@ -0,0 +1,37 @@
+use dioxus::prelude::*;
+
+/// Default styles of the `edit-*` classes of [`Theme::bootstrap`](super::Theme::bootstrap),
+/// with light and dark variants. See [`ColorScheme`](super::ColorScheme) to pin one of them.
+pub const KIT_STYLESHEET: Asset = asset!("/assets/admin-ui-kit.css");
+
+/// Links [`KIT_STYLESHEET`] into the document head. Render it once, e.g. in the app root.
+#[component]
+pub fn KitStylesheet() -> Element {
+    rsx! {
+        document::Stylesheet { href: KIT_STYLESHEET }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Theme;
+
+    const CSS: &str = include_str!("../../assets/admin-ui-kit.css");
+
+    #[test]
+    fn styles_every_kit_class() {
+        let theme = Theme::bootstrap();
+
+        for class in [
+            theme.wrapper,
+            theme.wrapper_error,
+            theme.validation_message,
+            theme.required_mark,
+            theme.char_counter,
+        ] {
+            for class in class.split(' ').filter(|class| class.starts_with("edit-")) {
+                assert!(CSS.contains(&format!(".{} ", class)), "{}", class);
+            }
+        }
+    }
+}
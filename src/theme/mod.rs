@@ -0,0 +1,6 @@
+mod theme_context;
+pub use theme_context::*;
+mod kit_stylesheet;
+pub use kit_stylesheet::*;
+mod color_scheme;
+pub use color_scheme::*;
@@ -0,0 +1,225 @@
+use dioxus::{core::Runtime, prelude::*};
+
+/// Class names and inline styles of kit components, shared through the Dioxus context.
+///
+/// Components look it up with [`Theme::current`] and fall back to [`Theme::bootstrap`]
+/// when no provider is registered. Custom themes start from a preset and override
+/// single tokens with the struct update syntax.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub wrapper: &'static str,
+    pub wrapper_error: &'static str,
+    pub input: &'static str,
+    pub input_read_only_style: &'static str,
+    pub input_error_style: &'static str,
+    pub select: &'static str,
+    pub checkbox: &'static str,
+    pub checkbox_style: &'static str,
+    pub input_group: &'static str,
+    pub input_group_text: &'static str,
+    pub input_button: &'static str,
+    pub validation_message: &'static str,
+    pub required_mark: &'static str,
+    pub help_text: &'static str,
+    pub spinner: &'static str,
+    pub spinner_style: &'static str,
+    pub char_counter: &'static str,
+    pub char_counter_exceeded: &'static str,
+    pub menu_trigger: &'static str,
+    pub menu: &'static str,
+    pub menu_item: &'static str,
+    pub menu_item_danger: &'static str,
+    pub menu_icon_style: &'static str,
+    pub combobox: &'static str,
+    pub combobox_style: &'static str,
+    pub combobox_list: &'static str,
+    pub combobox_list_style: &'static str,
+    pub combobox_group: &'static str,
+    pub combobox_option: &'static str,
+    pub combobox_option_active: &'static str,
+    pub combobox_hint: &'static str,
+    pub chips: &'static str,
+    pub chip: &'static str,
+    pub chip_remove: &'static str,
+    pub option_group_header: &'static str,
+    pub checkbox_list_item: &'static str,
+    pub checkbox_list_label: &'static str,
+    pub dual_list: &'static str,
+    pub dual_list_column: &'static str,
+    pub dual_list_pane: &'static str,
+    pub dual_list_pane_style: &'static str,
+    pub dual_list_header: &'static str,
+    pub dual_list_item: &'static str,
+    pub dual_list_item_active: &'static str,
+    pub dual_list_item_focused_style: &'static str,
+    pub dual_list_buttons: &'static str,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::bootstrap()
+    }
+}
+
+impl Theme {
+    /// Bootstrap 5 classes, the markup the kit has always rendered.
+    pub fn bootstrap() -> Self {
+        Self {
+            wrapper: "edit-wrapper",
+            wrapper_error: "edit-wrapper-validation-error",
+            input: "form-control",
+            input_read_only_style: "color: lightgray;font-weight: bold;",
+            input_error_style: "color: red",
+            select: "form-select",
+            checkbox: "form-check-input",
+            checkbox_style: " box-shadow: 0 0 1px gray;  margin-left: 5px; cursor: pointer;",
+            input_group: "input-group",
+            input_group_text: "input-group-text",
+            input_button: "btn btn-outline-secondary",
+            validation_message: "edit-validation-err-message",
+            required_mark: "edit-required-mark",
+            help_text: "form-text",
+            spinner: "spinner-border spinner-border-sm",
+            spinner_style: "margin-left: 5px;",
+            char_counter: "edit-char-counter text-muted",
+            char_counter_exceeded: "edit-char-counter text-danger",
+            menu_trigger: "btn btn-sm",
+            menu: "dropdown-menu show",
+            menu_item: "dropdown-item",
+            menu_item_danger: "dropdown-item text-danger",
+            menu_icon_style: "margin-right: 5px;",
+            combobox: "dropdown",
+            combobox_style: "position: relative;",
+            combobox_list: "dropdown-menu show w-100",
+            combobox_list_style: "max-height: 300px; overflow-y: auto;",
+            combobox_group: "dropdown-header",
+            combobox_option: "dropdown-item",
+            combobox_option_active: "dropdown-item active",
+            combobox_hint: "dropdown-item-text text-muted",
+            chips: "d-flex flex-wrap gap-1 mb-1",
+            chip: "badge rounded-pill text-bg-secondary",
+            chip_remove: "btn btn-sm btn-link p-0 ms-1 text-reset text-decoration-none",
+            option_group_header: "small fw-semibold text-body-secondary mt-2 mb-1",
+            checkbox_list_item: "form-check",
+            checkbox_list_label: "form-check-label",
+            dual_list: "d-flex gap-2 align-items-center",
+            dual_list_column: "flex-fill",
+            dual_list_pane: "list-group",
+            dual_list_pane_style: "max-height: 300px; overflow-y: auto;",
+            dual_list_header: "fw-bold",
+            dual_list_item: "list-group-item list-group-item-action",
+            dual_list_item_active: "list-group-item list-group-item-action active",
+            dual_list_item_focused_style: "box-shadow: inset 0 0 0 2px var(--bs-focus-ring-color);",
+            dual_list_buttons: "d-flex flex-column gap-1",
+        }
+    }
+
+    /// No classes and no inline styles: the markup is styled from outside,
+    /// e.g. with Tailwind classes set on top of this preset.
+    pub fn unstyled() -> Self {
+        Self {
+            wrapper: "",
+            wrapper_error: "",
+            input: "",
+            input_read_only_style: "",
+            input_error_style: "",
+            select: "",
+            checkbox: "",
+            checkbox_style: "",
+            input_group: "",
+            input_group_text: "",
+            input_button: "",
+            validation_message: "",
+            required_mark: "",
+            help_text: "",
+            spinner: "",
+            spinner_style: "",
+            char_counter: "",
+            char_counter_exceeded: "",
+            menu_trigger: "",
+            menu: "",
+            menu_item: "",
+            menu_item_danger: "",
+            menu_icon_style: "",
+            combobox: "",
+            combobox_style: "",
+            combobox_list: "",
+            combobox_list_style: "",
+            combobox_group: "",
+            combobox_option: "",
+            combobox_option_active: "",
+            combobox_hint: "",
+            chips: "",
+            chip: "",
+            chip_remove: "",
+            option_group_header: "",
+            checkbox_list_item: "",
+            checkbox_list_label: "",
+            dual_list: "",
+            dual_list_column: "",
+            dual_list_pane: "",
+            dual_list_pane_style: "",
+            dual_list_header: "",
+            dual_list_item: "",
+            dual_list_item_active: "",
+            dual_list_item_focused_style: "",
+            dual_list_buttons: "",
+        }
+    }
+
+    pub fn current() -> Self {
+        let in_scope = Runtime::try_current()
+            .and_then(|runtime| runtime.try_current_scope_id())
+            .is_some();
+
+        if !in_scope {
+            return Self::default();
+        }
+
+        try_consume_context::<Self>().unwrap_or_default()
+    }
+}
+
+pub fn use_theme_provider(theme: impl FnOnce() -> Theme) -> Theme {
+    use_context_provider(theme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{InputValueComponent, input_bool};
+    use crate::test_utils::*;
+    use crate::types::InputValue;
+
+    fn app() -> Element {
+        use_theme_provider(|| Theme {
+            input: "rounded border px-2",
+            ..Theme::unstyled()
+        });
+
+        let value = use_signal(|| InputValue::new(0).set_min_value_mut(1));
+
+        let input = InputValueComponent::new("Amount")
+            .set_detailed_value(&*value.read())
+            .on_input(|_| {})
+            .render();
+
+        rsx! {
+            {input}
+            {input_bool("Enabled", true, EventHandler::new(|_| {}))}
+        }
+    }
+
+    #[test]
+    fn renders_with_provided_theme() {
+        let html = ComponentHarness::new(app).render_html();
+
+        assert!(html.contains("class=\"rounded border px-2\""));
+        assert!(html.contains("must be ≥ 1"));
+        assert!(!html.contains("form-"));
+        assert!(!html.contains("edit-wrapper"));
+        assert!(!html.contains("edit-validation"));
+        assert!(!html.contains("color: red"));
+        assert!(!html.contains("box-shadow"));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{cell::Cell, fmt::Debug, rc::Rc, time::Duration};
+
+use dioxus::{core::Task, prelude::*};
+
+use super::{AsyncValueValidator, Clock};
+use crate::types::ValidationError;
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AsyncValidationState {
+    Idle,
+    Pending(String),
+    Done(String, Result<(), ValidationError>),
+}
+
+/// Runs an [`AsyncValueValidator`] for the values typed into an input.
+///
+/// Every new value cancels the check of the previous one, waits for the debounce interval
+/// and only then calls the validator. The outcome is kept in a signal, so components
+/// which rendered the pending state are re-rendered once the result arrives.
+///
+/// Must be created inside a component, e.g. in the initializer of `use_signal`.
+#[derive(Clone)]
+pub struct AsyncValidator {
+    validator: Rc<dyn AsyncValueValidator>,
+    debounce: Duration,
+    state: Signal<AsyncValidationState>,
+    task: Rc<Cell<Option<Task>>>,
+}
+
+impl AsyncValidator {
+    pub fn new(validator: impl AsyncValueValidator + 'static) -> Self {
+        Self {
+            validator: Rc::new(validator),
+            debounce: DEFAULT_DEBOUNCE,
+            state: Signal::new(AsyncValidationState::Idle),
+            task: Rc::new(Cell::new(None)),
+        }
+    }
+
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn check(&self, value: &str) {
+        self.cancel_task();
+
+        let value = value.to_string();
+        let mut state = self.state;
+        state.set(AsyncValidationState::Pending(value.clone()));
+
+        let validator = self.validator.clone();
+        let debounce = self.debounce;
+        let clock = Clock::current();
+
+        let task = spawn(async move {
+            clock.sleep(debounce).await;
+            let result = validator.validate(value.clone()).await;
+            state.set(AsyncValidationState::Done(value, result));
+        });
+
+        self.task.set(Some(task));
+    }
+
+    /// Drops the running check, e.g. because the value became invalid synchronously.
+    pub fn cancel(&self) {
+        self.cancel_task();
+
+        let mut state = self.state;
+        if *state.peek() != AsyncValidationState::Idle {
+            state.set(AsyncValidationState::Idle);
+        }
+    }
+
+    pub fn get_state(&self) -> AsyncValidationState {
+        self.state.read().clone()
+    }
+
+    pub fn is_pending(&self, value: &str) -> bool {
+        match &*self.state.read() {
+            AsyncValidationState::Pending(pending) => pending == value,
+            _ => false,
+        }
+    }
+
+    /// Result of the finished check of `value`. While the check is running, or the last one
+    /// was for another value, `value` is not verified yet and [`ValidationError::pending`]
+    /// is returned. Nothing is checked while idle, e.g. for the initial value.
+    pub fn get_result(&self, value: &str) -> Result<(), ValidationError> {
+        match &*self.state.read() {
+            AsyncValidationState::Idle => Ok(()),
+            AsyncValidationState::Done(checked, result) if checked == value => result.clone(),
+            _ => Err(ValidationError::pending()),
+        }
+    }
+
+    fn cancel_task(&self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+    }
+}
+
+impl PartialEq for AsyncValidator {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl Eq for AsyncValidator {}
+
+impl Debug for AsyncValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncValidator")
+            .field("debounce", &self.debounce)
+            .field("state", &*self.state.peek())
+            .finish()
+    }
+}
@@ -0,0 +1,22 @@
+use std::{future::Future, pin::Pin};
+
+use crate::types::ValidationError;
+
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), ValidationError>>>>;
+
+/// Check which needs a round trip, e.g. whether a username is still free.
+///
+/// Implemented for `Fn(String) -> impl Future<Output = Result<(), ValidationError>>`.
+pub trait AsyncValueValidator {
+    fn validate(&self, value: String) -> ValidationFuture;
+}
+
+impl<TFn, TFuture> AsyncValueValidator for TFn
+where
+    TFn: Fn(String) -> TFuture,
+    TFuture: Future<Output = Result<(), ValidationError>> + 'static,
+{
+    fn validate(&self, value: String) -> ValidationFuture {
+        Box::pin(self(value))
+    }
+}
@@ -0,0 +1,50 @@
+use std::{future::Future, pin::Pin, rc::Rc, time::Duration};
+
+use dioxus::{core::Runtime, prelude::*};
+
+use super::sleep;
+
+/// Source of the timers a [`Clock`] waits on.
+pub trait Timer {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()>>>;
+}
+
+/// Clock of the debounce intervals, shared through the Dioxus context.
+///
+/// Kit components look it up with [`Clock::current`] and wait on the timer of the platform
+/// when no provider is registered. Tests provide a paused one, see `ManualClock`.
+#[derive(Clone, Default)]
+pub struct Clock {
+    timer: Option<Rc<dyn Timer>>,
+}
+
+impl Clock {
+    pub fn new(timer: impl Timer + 'static) -> Self {
+        Self {
+            timer: Some(Rc::new(timer)),
+        }
+    }
+
+    pub fn current() -> Self {
+        let in_scope = Runtime::try_current()
+            .and_then(|runtime| runtime.try_current_scope_id())
+            .is_some();
+
+        if !in_scope {
+            return Self::default();
+        }
+
+        try_consume_context::<Self>().unwrap_or_default()
+    }
+
+    pub async fn sleep(&self, duration: Duration) {
+        match self.timer.as_ref() {
+            Some(timer) => timer.sleep(duration).await,
+            None => sleep(duration).await,
+        }
+    }
+}
+
+pub fn use_clock_provider(clock: impl FnOnce() -> Clock) -> Clock {
+    use_context_provider(clock)
+}
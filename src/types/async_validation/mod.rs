@@ -0,0 +1,8 @@
+mod async_value_validator;
+pub use async_value_validator::*;
+mod async_validator;
+pub use async_validator::*;
+mod sleep;
+use sleep::*;
+mod clock;
+pub use clock::*;
@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Desktop, mobile and liveview apps run on different executors, so the kit keeps its own
+/// timer thread instead of depending on one of them.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    timer_thread::Sleep::new(duration).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod timer_thread {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            Arc, Mutex, OnceLock,
+            mpsc::{self, RecvTimeoutError, Sender},
+        },
+        task::{Context, Poll, Waker},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// Waker of a registered timer. A sleep registers once and only swaps the waker
+    /// when it is polled by another task.
+    type SharedWaker = Arc<Mutex<Waker>>;
+
+    static TIMER: OnceLock<Sender<(Instant, SharedWaker)>> = OnceLock::new();
+
+    pub(super) struct Sleep {
+        deadline: Instant,
+        waker: Option<SharedWaker>,
+    }
+
+    impl Sleep {
+        pub fn new(duration: Duration) -> Self {
+            Self {
+                deadline: Instant::now() + duration,
+                waker: None,
+            }
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+
+            match self.waker.as_ref() {
+                Some(waker) => {
+                    let mut waker = waker.lock().unwrap_or_else(|err| err.into_inner());
+                    if !waker.will_wake(cx.waker()) {
+                        *waker = cx.waker().clone();
+                    }
+                }
+                None => {
+                    let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                    // The thread only exits once the sender is dropped, which never happens
+                    // for a static.
+                    let _ = get_timer().send((self.deadline, waker.clone()));
+                    self.waker = Some(waker);
+                }
+            }
+
+            // The deadline may have passed while the waker was swapped.
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+
+            Poll::Pending
+        }
+    }
+
+    fn get_timer() -> &'static Sender<(Instant, SharedWaker)> {
+        TIMER.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+            thread::Builder::new()
+                .name("admin-ui-kit-timer".to_string())
+                .spawn(move || run(receiver))
+                .expect("Failed to start the timer thread");
+            sender
+        })
+    }
+
+    fn run(receiver: mpsc::Receiver<(Instant, SharedWaker)>) {
+        let mut timers: Vec<(Instant, SharedWaker)> = Vec::new();
+
+        loop {
+            let received = match timers.iter().map(|(deadline, _)| *deadline).min() {
+                Some(next) => receiver.recv_timeout(next.saturating_duration_since(Instant::now())),
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match received {
+                Ok(timer) => timers.push(timer),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+
+            let now = Instant::now();
+            timers.retain(|(deadline, waker)| {
+                if *deadline > now {
+                    return true;
+                }
+
+                waker
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .wake_by_ref();
+                false
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+        time::{Duration, Instant},
+    };
+
+    use super::timer_thread::Sleep;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    #[test]
+    fn wakes_after_the_duration() {
+        let started = Instant::now();
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut context = Context::from_waker(&waker);
+        let mut sleep = std::pin::pin!(Sleep::new(Duration::from_millis(20)));
+
+        while sleep.as_mut().poll(&mut context) == Poll::Pending {
+            thread::park();
+        }
+
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn wakes_only_the_latest_waker() {
+        let first = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let first_waker = Waker::from(first.clone());
+        let mut sleep = std::pin::pin!(Sleep::new(Duration::from_millis(20)));
+
+        assert_eq!(
+            sleep.as_mut().poll(&mut Context::from_waker(&first_waker)),
+            Poll::Pending
+        );
+
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut context = Context::from_waker(&waker);
+        while sleep.as_mut().poll(&mut context) == Poll::Pending {
+            thread::park();
+        }
+
+        assert_eq!(first.0.load(Ordering::SeqCst), 0);
+    }
+}
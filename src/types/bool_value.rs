@@ -0,0 +1,63 @@
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+
+use super::{DetailedValidator, FormField};
+
+/// Checkbox state with the initial value kept for dirty tracking.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct BoolValue {
+    init_value: bool,
+    value: bool,
+}
+
+impl BoolValue {
+    pub fn new(value: bool) -> Self {
+        Self {
+            init_value: value,
+            value,
+        }
+    }
+
+    pub fn get_value(&self) -> bool {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: bool) {
+        self.value = value;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.init_value != self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = self.init_value;
+    }
+
+    pub fn commit(&mut self) {
+        self.init_value = self.value;
+    }
+}
+
+impl From<bool> for BoolValue {
+    fn from(value: bool) -> Self {
+        Self::new(value)
+    }
+}
+
+impl ValueValidator for BoolValue {
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        Ok(())
+    }
+}
+
+impl DetailedValidator for BoolValue {}
+
+impl FormField for BoolValue {
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+}
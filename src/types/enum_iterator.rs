@@ -1,6 +1,10 @@
 pub trait EnumIterator {
     type TItem;
 
+    /// Stable name of the enum in translation keys, see `MessageKey::EnumValue`.
+    /// Enums keeping the default share one key space for their values.
+    const NAME: &'static str = "";
+
     fn get_value(&self) -> Self::TItem;
     fn get_all() -> &'static [Self::TItem];
 }
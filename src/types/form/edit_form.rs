@@ -0,0 +1,231 @@
+use std::ops::{Deref, DerefMut};
+
+use super::*;
+
+pub type FormRef<'s> = Form<&'s (dyn FormField + 'static)>;
+pub type FormMut<'s> = Form<&'s mut (dyn FormField + 'static)>;
+
+/// Aggregates validation and change tracking of the fields of an edit page.
+///
+/// [`FormRef`] borrows the fields to decide whether the Save button is enabled during render.
+/// [`FormMut`] borrows them mutably and additionally allows [`Form::reset`].
+pub struct Form<TField: Deref<Target = dyn FormField>> {
+    fields: Vec<(&'static str, TField)>,
+}
+
+impl<TField: Deref<Target = dyn FormField>> Default for Form<TField> {
+    fn default() -> Self {
+        Self { fields: Vec::new() }
+    }
+}
+
+impl<TField: Deref<Target = dyn FormField>> Form<TField> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, name: &'static str, field: TField) -> Self {
+        self.fields.push((name, field));
+        self
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .iter()
+            .all(|(_, field)| field.validate_field().is_ok())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|(_, field)| field.is_dirty())
+    }
+
+    /// `true` while an async check of any field is running. Such a form is not submittable yet.
+    pub fn is_pending(&self) -> bool {
+        self.fields.iter().any(|(_, field)| field.is_pending())
+    }
+
+    pub fn can_be_submitted(&self) -> bool {
+        self.is_dirty() && self.is_valid() && !self.is_pending()
+    }
+
+    pub fn get_errors(&self) -> FieldErrors {
+        let mut result = FieldErrors::new();
+
+        for (name, field) in self.fields.iter() {
+            if let Err(err) = field.validate_field() {
+                result.insert(name, err);
+            }
+        }
+
+        result
+    }
+
+    pub fn submit<TResult>(
+        &self,
+        on_submit: impl FnOnce() -> TResult,
+    ) -> Result<TResult, FieldErrors> {
+        let errors = self.get_errors();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(on_submit())
+    }
+}
+
+impl<TField: DerefMut<Target = dyn FormField>> Form<TField> {
+    pub fn reset(&mut self) {
+        for (_, field) in self.fields.iter_mut() {
+            field.reset();
+        }
+    }
+
+    pub fn mark_touched(&mut self) {
+        for (_, field) in self.fields.iter_mut() {
+            field.mark_touched();
+        }
+    }
+
+    /// Like [`Form::submit`], but marks all fields touched first, whatever the outcome,
+    /// so blank required ones show their "required" message after a failed attempt.
+    pub fn attempt_submit<TResult>(
+        &mut self,
+        on_submit: impl FnOnce() -> TResult,
+    ) -> Result<TResult, FieldErrors> {
+        self.mark_touched();
+        self.submit(on_submit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::types::{AsyncValidator, InputValue, InputValueOpt, SelectEnumValueOpt, TimeOffset};
+    use dioxus::prelude::*;
+
+    struct EditModel {
+        amount: InputValue<i32>,
+        comment: InputValueOpt<i32>,
+        offset: SelectEnumValueOpt<TimeOffset>,
+    }
+
+    fn create_model() -> EditModel {
+        EditModel {
+            amount: InputValue::new(10).set_min_value_mut(1),
+            comment: InputValueOpt::new(None),
+            offset: SelectEnumValueOpt::new(None).allow_null_result(false),
+        }
+    }
+
+    #[test]
+    fn collects_errors_of_invalid_fields() {
+        let mut model = create_model();
+        model.amount.set_string_value("0".to_string());
+
+        let form = FormRef::new()
+            .register("amount", &model.amount)
+            .register("comment", &model.comment)
+            .register("offset", &model.offset);
+
+        let errors = form.get_errors();
+
+        assert!(!form.is_valid());
+        assert!(form.is_dirty());
+        assert_eq!(errors.len(), 2);
+        assert!(errors.has_error("amount"));
+        assert!(errors.has_error("offset"));
+        assert!(form.submit(|| ()).is_err());
+    }
+
+    #[test]
+    fn submits_valid_form() {
+        let mut model = create_model();
+        model.offset.set_value(Some(TimeOffset::UtcPlus3));
+
+        let form = FormRef::new()
+            .register("amount", &model.amount)
+            .register("offset", &model.offset);
+
+        assert!(form.can_be_submitted());
+        assert_eq!(form.submit(|| 42).ok(), Some(42));
+    }
+
+    #[test]
+    fn resets_fields_to_initial_values() {
+        let mut model = create_model();
+        model.amount.set_string_value("15".to_string());
+        model.offset.set_value(Some(TimeOffset::UtcPlus3));
+
+        let mut form = FormMut::new()
+            .register("amount", &mut model.amount)
+            .register("offset", &mut model.offset);
+
+        form.reset();
+        assert!(!form.is_dirty());
+
+        assert_eq!(model.amount.get_value(), Some(10));
+        assert!(model.offset.get_value().is_none());
+    }
+
+    #[test]
+    fn submit_attempt_marks_fields_touched() {
+        let mut model = create_model();
+        model.amount = InputValue::from_str("");
+        assert!(!model.amount.is_touched());
+
+        let result = FormMut::new()
+            .register("amount", &mut model.amount)
+            .register("comment", &mut model.comment)
+            .attempt_submit(|| ());
+
+        assert!(result.unwrap_err().has_error("amount"));
+        assert!(model.amount.is_touched());
+        assert!(model.comment.is_touched());
+
+        model.amount.reset();
+        assert!(!model.amount.is_touched());
+    }
+
+    thread_local! {
+        static VALIDATOR: FakeAsyncValidator = FakeAsyncValidator::new().hold();
+    }
+
+    fn app_with_pending_check() -> Element {
+        let mut name = use_signal(|| {
+            let validator = VALIDATOR.with(|validator| validator.clone());
+            InputValue::new("guest".to_string())
+                .with_async_validator(AsyncValidator::new(validator))
+        });
+        let mut submitted = use_signal(|| 0);
+
+        rsx! {
+            button { onclick: move |_| name.write().set_string_value("trader".to_string()) }
+            button {
+                onclick: move |_| {
+                    let result = FormRef::new().register("name", &*name.read()).submit(|| ());
+                    if result.is_ok() {
+                        submitted += 1;
+                    }
+                },
+            }
+            div { "submitted: {submitted}" }
+        }
+    }
+
+    #[test]
+    fn does_not_submit_during_pending_check() {
+        let mut harness = ComponentHarness::new(app_with_pending_check);
+
+        harness.fire_click_at(0);
+        harness.fire_click_at(1);
+        assert!(harness.render_html().contains("submitted: 0"));
+
+        VALIDATOR.with(|validator| validator.release());
+        harness.run_tasks();
+
+        harness.fire_click_at(1);
+        assert!(harness.render_html().contains("submitted: 1"));
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use rust_common::validators::ValueValidationResult;
+
+#[derive(Default)]
+pub struct FieldErrors {
+    errors: BTreeMap<&'static str, ValueValidationResult>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, field_name: &'static str, err: ValueValidationResult) {
+        self.errors.insert(field_name, err);
+    }
+
+    pub fn get(&self, field_name: &str) -> Option<&ValueValidationResult> {
+        self.errors.get(field_name)
+    }
+
+    pub fn has_error(&self, field_name: &str) -> bool {
+        self.errors.contains_key(field_name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ValueValidationResult)> {
+        self.errors.iter().map(|(name, err)| (*name, err))
+    }
+}
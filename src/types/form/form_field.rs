@@ -0,0 +1,18 @@
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+
+pub trait FormField: ValueValidator {
+    fn validate_field(&self) -> Result<(), ValueValidationResult> {
+        self.validate_value()
+    }
+
+    fn is_dirty(&self) -> bool;
+
+    fn reset(&mut self);
+
+    /// Called on a submit attempt so blank required fields show their errors.
+    fn mark_touched(&mut self) {}
+
+    fn is_pending(&self) -> bool {
+        false
+    }
+}
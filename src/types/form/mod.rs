@@ -0,0 +1,6 @@
+mod form_field;
+pub use form_field::*;
+mod field_errors;
+pub use field_errors::*;
+mod edit_form;
+pub use edit_form::*;
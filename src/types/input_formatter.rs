@@ -0,0 +1,14 @@
+use super::InputMask;
+
+/// Lets an input value rewrite what the user typed once the input loses focus,
+/// e.g. to apply thousands separators and a fixed precision.
+pub trait InputFormatter {
+    fn format_on_blur(&self) -> Option<String> {
+        None
+    }
+
+    /// Mask the input shows the stored (unmasked) value with.
+    fn get_input_mask(&self) -> Option<&InputMask> {
+        None
+    }
+}
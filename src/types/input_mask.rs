@@ -0,0 +1,208 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MaskSlot {
+    Digit,
+    Letter,
+    AlphaNumeric,
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::AlphaNumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// Mask of a formatted text field, e.g. `+1 (###) ###-####` or `AA## #### #### ####`.
+///
+/// `#` is a digit, `A` is a letter, `*` is a letter or a digit, `\` escapes the next
+/// character; everything else is a literal inserted by the mask. By default the value
+/// is stored without literals, see [`InputMask::keep_literals`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMask {
+    slots: Vec<MaskSlot>,
+    keep_literals: bool,
+}
+
+impl InputMask {
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            let slot = match c {
+                '#' => MaskSlot::Digit,
+                'A' => MaskSlot::Letter,
+                '*' => MaskSlot::AlphaNumeric,
+                '\\' => match chars.next() {
+                    Some(c) => MaskSlot::Literal(c),
+                    None => break,
+                },
+                c => MaskSlot::Literal(c),
+            };
+            slots.push(slot);
+        }
+
+        Self {
+            slots,
+            keep_literals: false,
+        }
+    }
+
+    /// Stores the value with literals, e.g. `10.0.0.1` instead of `10001`.
+    pub fn keep_literals(mut self) -> Self {
+        self.keep_literals = true;
+        self
+    }
+
+    /// Mask with `_` instead of each placeholder, shown in the empty input.
+    pub fn get_placeholder(&self) -> String {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                MaskSlot::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    pub fn get_placeholders_amount(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| !matches!(slot, MaskSlot::Literal(_)))
+            .count()
+    }
+
+    /// Value to store for the text typed into the input.
+    pub fn unmask(&self, src: &str) -> String {
+        self.fit(src, self.keep_literals)
+    }
+
+    /// Text to show in the input for the stored value.
+    pub fn apply(&self, value: &str) -> String {
+        self.fit(value, true)
+    }
+
+    pub fn is_complete(&self, value: &str) -> bool {
+        self.fit(value, false).chars().count() == self.get_placeholders_amount()
+    }
+
+    /// For each caret position in `src` typed into the input, the caret position
+    /// in `apply(unmask(src))`, so the caret stays after the same typed character.
+    pub fn get_caret_map(&self, src: &str) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut prefix = String::new();
+        result.push(0);
+
+        for c in src.chars() {
+            prefix.push(c);
+            let typed = self.fit(prefix.as_str(), false).chars().count();
+            result.push(self.get_caret_after(typed));
+        }
+
+        result
+    }
+
+    fn get_caret_after(&self, placeholders: usize) -> usize {
+        if placeholders == 0 {
+            return 0;
+        }
+
+        let mut found = 0;
+        for (index, slot) in self.slots.iter().enumerate() {
+            if !matches!(slot, MaskSlot::Literal(_)) {
+                found += 1;
+                if found == placeholders {
+                    return index + 1;
+                }
+            }
+        }
+
+        self.slots.len()
+    }
+
+    /// Puts the characters of `src` into the slots: typed literals are consumed,
+    /// missing ones are inserted before the next accepted character,
+    /// characters which do not fit the slot are dropped.
+    fn fit(&self, src: &str, with_literals: bool) -> String {
+        let mut result = String::new();
+        let mut index = 0;
+
+        'chars: for c in src.chars() {
+            let mut literals = String::new();
+
+            while let Some(slot) = self.slots.get(index) {
+                match slot {
+                    MaskSlot::Literal(literal) => {
+                        literals.push(*literal);
+                        index += 1;
+
+                        if *literal == c {
+                            if with_literals {
+                                result.push_str(literals.as_str());
+                            }
+                            continue 'chars;
+                        }
+                    }
+                    slot => {
+                        if slot.accepts(c) {
+                            if with_literals {
+                                result.push_str(literals.as_str());
+                            }
+                            result.push(c);
+                            index += 1;
+                        } else {
+                            index -= literals.chars().count();
+                        }
+                        continue 'chars;
+                    }
+                }
+            }
+
+            index -= literals.chars().count();
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_and_removes_literals() {
+        let mask = InputMask::new("+1 (###) ###-####");
+
+        assert_eq!(mask.unmask("+1 (555) 12"), "55512");
+        assert_eq!(mask.unmask("555x12"), "55512");
+        assert_eq!(mask.apply("55512"), "+1 (555) 12");
+        assert_eq!(mask.get_placeholder(), "+1 (___) ___-____");
+        assert!(!mask.is_complete("55512"));
+        assert!(mask.is_complete("5551234567"));
+    }
+
+    #[test]
+    fn keeps_literals_if_requested() {
+        let mask = InputMask::new("###.###.###.###").keep_literals();
+        assert_eq!(mask.unmask("192168001"), "192.168.001");
+        assert_eq!(mask.unmask("192.168.0"), "192.168.0");
+
+        let mask = InputMask::new("AA\\#**");
+        assert_eq!(mask.unmask("ab#1c"), "ab1c");
+        assert_eq!(mask.apply("ab1c"), "ab#1c");
+    }
+
+    #[test]
+    fn maps_caret_to_the_typed_character() {
+        let mask = InputMask::new("####-####");
+
+        // "12345" typed with the caret after "5": the dash is inserted before it
+        assert_eq!(mask.get_caret_map("12345"), vec![0, 1, 2, 3, 4, 6]);
+        assert_eq!(mask.get_caret_map("1234-5")[6], 6);
+    }
+}
@@ -0,0 +1,6 @@
+/// Lets a numeric input change its value by steps: +/− buttons, arrow keys and mouse wheel.
+pub trait InputStepper {
+    /// Text of the value moved by `steps` steps and clamped to the bounds,
+    /// or `None` if the value would not change.
+    fn get_stepped_string(&self, steps: i32) -> Option<String>;
+}
@@ -3,6 +3,11 @@ use std::{fmt::Display, marker::PhantomData, rc::Rc, str::FromStr};
 use rust_common::validators::{ValueValidationResult, ValueValidator};
 use rust_extensions::AsStr;
 
+use super::{
+    AsyncValidator, DetailedValidator, FormField, InputFormatter, InputMask, InputStepper,
+    NumberFormat, StepValue, ValidationError, ValueRule, ValueRules,
+};
+
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct InputValue<T: PartialEq + PartialOrd + Display + FromStr + 'static> {
     init_value: Rc<String>,
@@ -10,6 +15,12 @@ pub struct InputValue<T: PartialEq + PartialOrd + Display + FromStr + 'static> {
     p: PhantomData<T>,
     min_value: Option<T>,
     max_value: Option<T>,
+    rules: ValueRules<T>,
+    async_validator: Option<AsyncValidator>,
+    mask: Option<InputMask>,
+    touched: bool,
+    number_format: Option<NumberFormat>,
+    step: Option<T>,
 }
 
 impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValue<T> {
@@ -22,6 +33,12 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValue<T> {
             p: PhantomData,
             min_value: None,
             max_value: None,
+            rules: ValueRules::default(),
+            async_validator: None,
+            mask: None,
+            touched: false,
+            number_format: None,
+            step: None,
         }
     }
 
@@ -40,11 +57,17 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValue<T> {
             p: PhantomData,
             min_value: None,
             max_value: None,
+            rules: ValueRules::default(),
+            async_validator: None,
+            mask: None,
+            touched: false,
+            number_format: None,
+            step: None,
         }
     }
 
     pub fn get_value(&self) -> Option<T> {
-        let result: Result<T, _> = self.value.parse();
+        let result: Result<T, _> = self.parse_value();
 
         match result {
             Ok(result) => Some(result),
@@ -52,6 +75,47 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValue<T> {
         }
     }
 
+    fn parse_value(&self) -> Result<T, T::Err> {
+        match self.number_format.as_ref() {
+            Some(number_format) => number_format.normalize(&self.value).parse(),
+            None => self.value.parse(),
+        }
+    }
+
+    pub fn with_number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+
+        if let Some(formatted) = self.get_formatted_value() {
+            let formatted = Rc::new(formatted);
+            if self.init_value == self.value {
+                self.init_value = formatted.clone();
+            }
+            self.value = formatted;
+        }
+
+        self
+    }
+
+    pub fn get_formatted_value(&self) -> Option<String> {
+        let number_format = self.number_format.as_ref()?;
+        let value = self.get_value()?;
+        Some(Self::format_number(
+            number_format,
+            value.to_string().as_str(),
+        ))
+    }
+
+    /// Precision applies to fractional types only: `1 234,00` would not parse back into an integer.
+    fn format_number(number_format: &NumberFormat, src: &str) -> String {
+        let formatted = number_format.format(src);
+
+        if number_format.normalize(&formatted).parse::<T>().is_ok() {
+            return formatted;
+        }
+
+        number_format.without_precision().format(src)
+    }
+
     pub fn set_min_value_mut(mut self, min_value: T) -> Self {
         self.min_value = Some(min_value);
         self
@@ -61,37 +125,188 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValue<T> {
         self
     }
 
+    pub fn set_step(mut self, step: T) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn with_rule(mut self, rule: impl ValueRule<T> + 'static) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// The value is stored unmasked and is valid only if all the placeholders are filled.
+    pub fn with_mask(mut self, mask: InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// The async check runs only for values which pass the synchronous validation.
+    pub fn with_async_validator(mut self, async_validator: AsyncValidator) -> Self {
+        self.async_validator = Some(async_validator);
+        self
+    }
+
+    pub fn is_validation_pending(&self) -> bool {
+        match self.async_validator.as_ref() {
+            Some(async_validator) => async_validator.is_pending(self.value.as_str()),
+            None => false,
+        }
+    }
+
+    fn check_async(&self) {
+        let Some(async_validator) = self.async_validator.as_ref() else {
+            return;
+        };
+
+        if self.value == self.init_value || self.validate_sync().is_err() {
+            async_validator.cancel();
+        } else {
+            async_validator.check(self.value.as_str());
+        }
+    }
+
     pub fn set_string_value(&mut self, value: String) {
         self.value = Rc::new(value);
+        self.touched = true;
+        self.check_async();
+    }
+
+    /// `true` once the user has typed into the field or a submit was attempted.
+    /// A blank required field reports its error only after that.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn mark_touched(&mut self) {
+        self.touched = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.init_value != self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = self.init_value.clone();
+        self.touched = false;
+        self.check_async();
+    }
+
+    pub fn commit(&mut self) {
+        self.init_value = self.value.clone();
+    }
+
+    pub fn value_can_be_saved(&self) -> Option<bool> {
+        if !self.is_dirty() {
+            return None;
+        }
+
+        Some(self.validate().is_ok())
     }
 
     pub fn validate(&self) -> Result<(), ValueValidationResult> {
+        self.validate_detailed()
+            .map_err(|err| err.to_value_validation_result())
+    }
+
+    pub fn validate_detailed(&self) -> Result<(), ValidationError> {
+        self.validate_sync()?;
+
+        match self.async_validator.as_ref() {
+            Some(async_validator) => async_validator.get_result(self.value.as_str()),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_sync(&self) -> Result<(), ValidationError> {
         if self.value.len() == 0 {
-            return Err(ValueValidationResult::Empty);
+            return Err(ValidationError::empty());
         }
 
-        let result: Result<T, _> = self.value.parse();
+        if let Some(mask) = self.mask.as_ref()
+            && !mask.is_complete(&self.value)
+        {
+            return Err(ValidationError::incomplete(mask.get_placeholder()));
+        }
+
+        let result: Result<T, _> = self.parse_value();
 
         let result = match result {
             Ok(result) => result,
             Err(_) => {
-                return Err(ValueValidationResult::IllegalChars);
+                return Err(ValidationError::illegal_chars());
             }
         };
 
         if let Some(min_value) = &self.min_value {
             if &result < min_value {
-                return Err(ValueValidationResult::MinValueViolation);
+                return Err(ValidationError::min_value(min_value));
             }
         }
 
         if let Some(max_value) = &self.max_value {
             if &result > max_value {
-                return Err(ValueValidationResult::MaxValueViolation);
+                return Err(ValidationError::max_value(max_value));
             }
         }
 
-        Ok(())
+        self.rules.check(&self.value, &result)
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + StepValue + 'static> InputValue<T> {
+    /// Current value moved by `steps` steps (1 if no step is set) and clamped to min/max.
+    /// Unparsable text is stepped from the min value or zero.
+    pub fn get_stepped_value(&self, steps: i32) -> T {
+        let step = self.step.unwrap_or_else(T::default_step);
+        let value = self
+            .get_value()
+            .unwrap_or_else(|| self.min_value.unwrap_or_default());
+
+        let result = match value.add_steps(step, steps) {
+            Some(result) => result,
+            None if steps < 0 => self.min_value.unwrap_or(value),
+            None => self.max_value.unwrap_or(value),
+        };
+
+        if let Some(min_value) = self.min_value
+            && result < min_value
+        {
+            return min_value;
+        }
+
+        if let Some(max_value) = self.max_value
+            && result > max_value
+        {
+            return max_value;
+        }
+
+        result
+    }
+
+    pub fn step_value(&mut self, steps: i32) {
+        if let Some(value) = self.get_stepped_string(steps) {
+            self.set_string_value(value);
+        }
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + StepValue + 'static> InputStepper
+    for InputValue<T>
+{
+    fn get_stepped_string(&self, steps: i32) -> Option<String> {
+        let value = self.get_stepped_value(steps).to_string();
+
+        let value = match self.number_format.as_ref() {
+            Some(number_format) => Self::format_number(number_format, value.as_str()),
+            None => value,
+        };
+
+        if value.as_str() == self.value.as_str() {
+            return None;
+        }
+
+        Some(value)
     }
 }
 
@@ -112,3 +327,234 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> ValueValidator for
         self.validate()
     }
 }
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> DetailedValidator for InputValue<T> {
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_detailed().err()
+    }
+
+    fn is_validation_pending(&self) -> bool {
+        self.is_validation_pending()
+    }
+
+    fn is_required(&self) -> bool {
+        true
+    }
+
+    fn is_touched(&self) -> bool {
+        self.is_touched()
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputFormatter for InputValue<T> {
+    fn format_on_blur(&self) -> Option<String> {
+        let formatted = self.get_formatted_value()?;
+
+        if formatted.as_str() == self.value.as_str() {
+            return None;
+        }
+
+        Some(formatted)
+    }
+
+    fn get_input_mask(&self) -> Option<&InputMask> {
+        self.mask.as_ref()
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> FormField for InputValue<T> {
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    fn mark_touched(&mut self) {
+        self.mark_touched();
+    }
+
+    fn is_pending(&self) -> bool {
+        self.is_validation_pending()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_changes_against_baseline() {
+        let mut value = InputValue::new(10).set_max_value_mut(100);
+        assert!(!value.is_dirty());
+        assert_eq!(value.value_can_be_saved(), None);
+
+        value.set_string_value("200".to_string());
+        assert!(value.is_dirty());
+        assert_eq!(value.value_can_be_saved(), Some(false));
+
+        value.set_string_value("20".to_string());
+        assert_eq!(value.value_can_be_saved(), Some(true));
+
+        value.commit();
+        assert!(!value.is_dirty());
+
+        value.set_string_value("30".to_string());
+        value.reset();
+        assert_eq!(value.get_value(), Some(20));
+    }
+
+    #[test]
+    fn runs_rules_after_parsing() {
+        let mut value = InputValue::new("trader".to_string())
+            .with_rule(crate::types::LengthRule::max(8))
+            .with_rule(|v: &String| {
+                if v.starts_with("admin") {
+                    return Err("reserved name".to_string());
+                }
+                Ok(())
+            });
+
+        assert!(value.validate().is_ok());
+
+        value.set_string_value("administrator".to_string());
+        assert!(value.validate().is_err());
+        assert_eq!(
+            value.validate_detailed().err().map(|err| err.get_message()),
+            Some("must be at most 8 characters".to_string())
+        );
+
+        value.set_string_value("admin".to_string());
+        assert_eq!(
+            value.validate_detailed().err(),
+            Some(ValidationError::custom("reserved name"))
+        );
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let mut value = InputValue::new(8)
+            .set_min_value_mut(0)
+            .set_max_value_mut(10)
+            .set_step(2);
+
+        assert_eq!(value.get_stepped_string(1).as_deref(), Some("10"));
+        assert_eq!(value.get_stepped_string(10).as_deref(), Some("10"));
+        assert_eq!(value.get_stepped_string(-10).as_deref(), Some("0"));
+
+        value.step_value(1);
+        assert_eq!(value.get_value(), Some(10));
+        assert_eq!(value.get_stepped_string(1), None);
+
+        value.set_string_value("abc".to_string());
+        assert_eq!(value.get_stepped_value(1), 2);
+    }
+
+    #[test]
+    fn parses_and_formats_with_number_format() {
+        let mut value = InputValue::new(1234.5)
+            .with_number_format(crate::types::NumberFormat::european().with_precision(2));
+
+        assert_eq!(value.as_str(), "1 234,50");
+        assert!(!value.is_dirty());
+
+        value.set_string_value("1 000 000,125".to_string());
+        assert_eq!(value.get_value(), Some(1000000.125));
+        assert!(value.validate().is_ok());
+        assert_eq!(value.format_on_blur().as_deref(), Some("1 000 000,13"));
+    }
+
+    #[test]
+    fn skips_precision_for_integers() {
+        let mut value = InputValue::new(1234i64)
+            .with_number_format(crate::types::NumberFormat::european().with_precision(2));
+
+        assert_eq!(value.as_str(), "1 234");
+        assert_eq!(value.get_stepped_string(1).as_deref(), Some("1 235"));
+
+        value.set_string_value("12345".to_string());
+        assert_eq!(value.format_on_blur().as_deref(), Some("12 345"));
+        assert!(value.validate().is_ok());
+    }
+}
+
+#[cfg(feature = "form-serde")]
+mod serde_impl {
+    use std::{fmt::Display, rc::Rc, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::InputValue;
+    use crate::types::ValueRules;
+
+    /// Rules, async validator, mask and number format are not serialized: re-apply them after deserialization.
+    #[derive(Serialize, Deserialize)]
+    struct InputValueState<T> {
+        value: String,
+        init_value: String,
+        min_value: Option<T>,
+        max_value: Option<T>,
+        #[serde(default)]
+        touched: bool,
+    }
+
+    impl<T: PartialEq + PartialOrd + Display + FromStr + Serialize + Clone + 'static> Serialize
+        for InputValue<T>
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            InputValueState {
+                value: self.value.to_string(),
+                init_value: self.init_value.to_string(),
+                min_value: self.min_value.clone(),
+                max_value: self.max_value.clone(),
+                touched: self.touched,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: PartialEq + PartialOrd + Display + FromStr + Deserialize<'de> + 'static>
+        Deserialize<'de> for InputValue<T>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = InputValueState::<T>::deserialize(deserializer)?;
+            Ok(Self {
+                init_value: Rc::new(state.init_value),
+                value: Rc::new(state.value),
+                p: std::marker::PhantomData,
+                min_value: state.min_value,
+                max_value: state.max_value,
+                rules: ValueRules::default(),
+                async_validator: None,
+                mask: None,
+                touched: state.touched,
+                number_format: None,
+                step: None,
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use rust_extensions::AsStr;
+
+        #[test]
+        fn round_trips_draft_state() {
+            let mut value = InputValue::new(10).set_min_value_mut(5);
+            value.set_string_value("3".to_string());
+
+            let json = serde_json::to_string(&value).unwrap();
+            let restored: InputValue<i32> = serde_json::from_str(json.as_str()).unwrap();
+
+            assert_eq!(restored.as_str(), "3");
+            assert!(restored.is_dirty());
+            assert!(restored.validate().is_err());
+
+            let mut restored = restored;
+            restored.reset();
+            assert_eq!(restored.get_value(), Some(10));
+        }
+    }
+}
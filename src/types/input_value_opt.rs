@@ -2,6 +2,11 @@ use std::{fmt::Display, marker::PhantomData, str::FromStr};
 
 use rust_common::validators::ValueValidationResult;
 
+use super::{
+    AsyncValidator, DetailedValidator, FormField, InputFormatter, InputMask, ValidationError,
+    ValueRule, ValueRules,
+};
+
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct InputValueOpt<T: PartialEq + PartialOrd + Display + FromStr + 'static> {
     init_value: String,
@@ -9,6 +14,10 @@ pub struct InputValueOpt<T: PartialEq + PartialOrd + Display + FromStr + 'static
     p: PhantomData<T>,
     min_value: Option<T>,
     max_value: Option<T>,
+    rules: ValueRules<T>,
+    async_validator: Option<AsyncValidator>,
+    mask: Option<InputMask>,
+    touched: bool,
 }
 
 impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
@@ -21,6 +30,10 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
                 p: PhantomData,
                 min_value: None,
                 max_value: None,
+                rules: ValueRules::default(),
+                async_validator: None,
+                mask: None,
+                touched: false,
             };
         };
 
@@ -31,6 +44,10 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
             p: PhantomData,
             min_value: None,
             max_value: None,
+            rules: ValueRules::default(),
+            async_validator: None,
+            mask: None,
+            touched: false,
         }
     }
 
@@ -62,12 +79,73 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
         self.max_value = Some(max_value);
     }
 
+    pub fn with_rule(mut self, rule: impl ValueRule<T> + 'static) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// The value is stored unmasked and is valid only if all the placeholders are filled.
+    pub fn with_mask(mut self, mask: InputMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// The async check runs only for values which pass the synchronous validation.
+    pub fn with_async_validator(mut self, async_validator: AsyncValidator) -> Self {
+        self.async_validator = Some(async_validator);
+        self
+    }
+
+    pub fn is_validation_pending(&self) -> bool {
+        match self.async_validator.as_ref() {
+            Some(async_validator) => async_validator.is_pending(self.value.as_str()),
+            None => false,
+        }
+    }
+
+    fn check_async(&self) {
+        let Some(async_validator) = self.async_validator.as_ref() else {
+            return;
+        };
+
+        if self.value == self.init_value || self.validate_sync().is_err() {
+            async_validator.cancel();
+        } else {
+            async_validator.check(self.value.as_str());
+        }
+    }
+
     pub fn set_value(&mut self, value: String) {
         self.value = value;
+        self.touched = true;
+        self.check_async();
+    }
+
+    /// `true` once the user has typed into the field or a submit was attempted.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn mark_touched(&mut self) {
+        self.touched = true;
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.init_value != self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = self.init_value.clone();
+        self.touched = false;
+        self.check_async();
+    }
+
+    pub fn commit(&mut self) {
+        self.init_value = self.value.clone();
     }
 
     pub fn value_can_be_saved(&self) -> Option<bool> {
-        if self.init_value == self.value {
+        if !self.is_dirty() {
             return None;
         }
         let result = match self.validate() {
@@ -82,8 +160,28 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
     }
 
     pub fn validate(&self) -> Result<(), ValueValidationResult> {
+        self.validate_detailed()
+            .map_err(|err| err.to_value_validation_result())
+    }
+
+    pub fn validate_detailed(&self) -> Result<(), ValidationError> {
+        self.validate_sync()?;
+
+        match self.async_validator.as_ref() {
+            Some(async_validator) => async_validator.get_result(self.value.as_str()),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_sync(&self) -> Result<(), ValidationError> {
         if self.value.len() == 0 {
-            return Err(ValueValidationResult::Empty);
+            return Err(ValidationError::empty());
+        }
+
+        if let Some(mask) = self.mask.as_ref()
+            && !mask.is_complete(&self.value)
+        {
+            return Err(ValidationError::incomplete(mask.get_placeholder()));
         }
 
         let result: Result<T, _> = self.value.parse();
@@ -91,23 +189,23 @@ impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputValueOpt<T> {
         let result = match result {
             Ok(result) => result,
             Err(_) => {
-                return Err(ValueValidationResult::IllegalChars);
+                return Err(ValidationError::illegal_chars());
             }
         };
 
         if let Some(min_value) = &self.min_value {
             if &result < min_value {
-                return Err(ValueValidationResult::MinValueViolation);
+                return Err(ValidationError::min_value(min_value));
             }
         }
 
         if let Some(max_value) = &self.max_value {
             if &result > max_value {
-                return Err(ValueValidationResult::MaxValueViolation);
+                return Err(ValidationError::max_value(max_value));
             }
         }
 
-        Ok(())
+        self.rules.check(&self.value, &result)
     }
 }
 
@@ -130,3 +228,124 @@ impl<T: PartialEq + PartialOrd + Display + FromStr> rust_common::validators::Val
         self.validate()
     }
 }
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> DetailedValidator
+    for InputValueOpt<T>
+{
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_detailed().err()
+    }
+
+    fn is_validation_pending(&self) -> bool {
+        self.is_validation_pending()
+    }
+
+    fn is_touched(&self) -> bool {
+        self.is_touched()
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> InputFormatter for InputValueOpt<T> {
+    fn get_input_mask(&self) -> Option<&InputMask> {
+        self.mask.as_ref()
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Display + FromStr + 'static> FormField for InputValueOpt<T> {
+    fn validate_field(&self) -> Result<(), ValueValidationResult> {
+        match self.validate() {
+            Err(ValueValidationResult::Empty) => Ok(()),
+            result => result,
+        }
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    fn mark_touched(&mut self) {
+        self.mark_touched();
+    }
+
+    fn is_pending(&self) -> bool {
+        self.is_validation_pending()
+    }
+}
+
+#[cfg(feature = "form-serde")]
+mod serde_impl {
+    use std::{fmt::Display, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::InputValueOpt;
+    use crate::types::ValueRules;
+
+    /// Rules, async validator and mask are not serialized: re-apply them after deserialization.
+    #[derive(Serialize, Deserialize)]
+    struct InputValueOptState<T> {
+        value: String,
+        init_value: String,
+        min_value: Option<T>,
+        max_value: Option<T>,
+        #[serde(default)]
+        touched: bool,
+    }
+
+    impl<T: PartialEq + PartialOrd + Display + FromStr + Serialize + Clone + 'static> Serialize
+        for InputValueOpt<T>
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            InputValueOptState {
+                value: self.value.clone(),
+                init_value: self.init_value.clone(),
+                min_value: self.min_value.clone(),
+                max_value: self.max_value.clone(),
+                touched: self.touched,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: PartialEq + PartialOrd + Display + FromStr + Deserialize<'de> + 'static>
+        Deserialize<'de> for InputValueOpt<T>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let state = InputValueOptState::<T>::deserialize(deserializer)?;
+            Ok(Self {
+                init_value: state.init_value,
+                value: state.value,
+                p: std::marker::PhantomData,
+                min_value: state.min_value,
+                max_value: state.max_value,
+                rules: ValueRules::default(),
+                async_validator: None,
+                mask: None,
+                touched: state.touched,
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trips_draft_state() {
+            let mut value = InputValueOpt::<u32>::new(None);
+            value.set_max_value_mut(100);
+            value.set_value("250".to_string());
+
+            let json = serde_json::to_string(&value).unwrap();
+            let restored: InputValueOpt<u32> = serde_json::from_str(json.as_str()).unwrap();
+
+            assert_eq!(restored, value);
+            assert!(restored.is_dirty());
+            assert!(restored.validate().is_err());
+        }
+    }
+}
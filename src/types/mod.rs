@@ -10,3 +10,27 @@ mod input_value_opt;
 pub use input_value_opt::*;
 mod select_value;
 pub use select_value::*;
+mod form;
+pub use form::*;
+mod value_rules;
+pub use value_rules::*;
+mod validation;
+pub use validation::*;
+mod number_format;
+pub use number_format::*;
+mod input_formatter;
+pub use input_formatter::*;
+mod money;
+pub use money::*;
+mod async_validation;
+pub use async_validation::*;
+mod step_value;
+pub use step_value::*;
+mod input_stepper;
+pub use input_stepper::*;
+mod input_mask;
+pub use input_mask::*;
+mod multi_select;
+pub use multi_select::*;
+mod bool_value;
+pub use bool_value::*;
@@ -0,0 +1,16 @@
+/// Symbol shown next to the money input. Unknown codes are shown as is.
+pub fn get_currency_symbol(currency: &str) -> &str {
+    match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "UAH" => "₴",
+        "PLN" => "zł",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "TRY" => "₺",
+        "BTC" => "₿",
+        _ => currency,
+    }
+}
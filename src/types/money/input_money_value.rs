@@ -0,0 +1,277 @@
+use std::rc::Rc;
+
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+use rust_extensions::AsStr;
+
+use super::{MoneyAmount, get_currency_symbol};
+use crate::types::{DetailedValidator, FormField, InputFormatter, ValidationError};
+
+/// Editable money amount in a fixed currency and scale. Unlike `InputValue<f64>`
+/// the typed string is parsed into [`MoneyAmount`], so no float rounding happens.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct InputMoneyValue {
+    init_value: Rc<String>,
+    value: Rc<String>,
+    currency: &'static str,
+    scale: u8,
+    min_value: Option<MoneyAmount>,
+    max_value: Option<MoneyAmount>,
+    step: Option<MoneyAmount>,
+    touched: bool,
+}
+
+impl InputMoneyValue {
+    pub fn new(amount: MoneyAmount, currency: &'static str) -> Self {
+        let value = Rc::new(amount.to_string());
+        Self {
+            init_value: value.clone(),
+            value,
+            currency,
+            scale: amount.get_scale(),
+            min_value: None,
+            max_value: None,
+            step: None,
+            touched: false,
+        }
+    }
+
+    pub fn empty(currency: &'static str, scale: u8) -> Self {
+        let value = Rc::new(String::new());
+        Self {
+            init_value: value.clone(),
+            value,
+            currency,
+            scale,
+            min_value: None,
+            max_value: None,
+            step: None,
+            touched: false,
+        }
+    }
+
+    pub fn set_min_value(mut self, min_value: MoneyAmount) -> Self {
+        self.min_value = Some(min_value);
+        self
+    }
+
+    pub fn set_max_value(mut self, max_value: MoneyAmount) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Value must be `min_value + n * step`, or `n * step` if there is no min value.
+    pub fn set_step(mut self, step: MoneyAmount) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn get_currency(&self) -> &'static str {
+        self.currency
+    }
+
+    pub fn get_currency_symbol(&self) -> &'static str {
+        get_currency_symbol(self.currency)
+    }
+
+    pub fn get_scale(&self) -> u8 {
+        self.scale
+    }
+
+    pub fn get_value(&self) -> Option<MoneyAmount> {
+        MoneyAmount::parse(self.value.as_str(), self.scale).ok()
+    }
+
+    pub fn set_string_value(&mut self, value: String) {
+        self.value = Rc::new(value);
+        self.touched = true;
+    }
+
+    /// Set by typing, see [`InputMoneyValue::mark_touched`] for submit attempts.
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn mark_touched(&mut self) {
+        self.touched = true;
+    }
+
+    /// `false` if the typed text can not become a valid amount of this scale
+    /// by typing more characters, e.g. it has too many decimal places.
+    pub fn accepts_input(&self, src: &str) -> bool {
+        let digits = src.trim().strip_prefix('-').unwrap_or(src.trim());
+
+        let (int_part, frac_part) = match digits.split_once(['.', ',']) {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (digits, None),
+        };
+
+        if !int_part.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+
+        match frac_part {
+            Some(frac_part) => {
+                self.scale > 0
+                    && frac_part.len() <= self.scale as usize
+                    && frac_part.chars().all(|c| c.is_ascii_digit())
+            }
+            None => true,
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.init_value != self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = self.init_value.clone();
+        self.touched = false;
+    }
+
+    pub fn commit(&mut self) {
+        self.init_value = self.value.clone();
+    }
+
+    pub fn value_can_be_saved(&self) -> Option<bool> {
+        if !self.is_dirty() {
+            return None;
+        }
+
+        Some(self.validate().is_ok())
+    }
+
+    pub fn validate(&self) -> Result<(), ValueValidationResult> {
+        self.validate_detailed()
+            .map_err(|err| err.to_value_validation_result())
+    }
+
+    pub fn validate_detailed(&self) -> Result<(), ValidationError> {
+        let value = MoneyAmount::parse(self.value.as_str(), self.scale)?;
+
+        if let Some(min_value) = &self.min_value
+            && &value < min_value
+        {
+            return Err(ValidationError::min_value(min_value));
+        }
+
+        if let Some(max_value) = &self.max_value
+            && &value > max_value
+        {
+            return Err(ValidationError::max_value(max_value));
+        }
+
+        if let Some(step) = &self.step {
+            let base = match self.min_value {
+                Some(min_value) => min_value,
+                None => MoneyAmount::zero(self.scale)?,
+            };
+            if !value.is_multiple_of(step, &base) {
+                return Err(ValidationError::step(step));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AsStr for InputMoneyValue {
+    fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ValueValidator for InputMoneyValue {
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        self.validate()
+    }
+}
+
+impl DetailedValidator for InputMoneyValue {
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_detailed().err()
+    }
+
+    fn is_required(&self) -> bool {
+        true
+    }
+
+    fn is_touched(&self) -> bool {
+        self.is_touched()
+    }
+}
+
+impl InputFormatter for InputMoneyValue {
+    fn format_on_blur(&self) -> Option<String> {
+        let formatted = self.get_value()?.to_string();
+
+        if formatted.as_str() == self.value.as_str() {
+            return None;
+        }
+
+        Some(formatted)
+    }
+}
+
+impl FormField for InputMoneyValue {
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    fn mark_touched(&mut self) {
+        self.mark_touched();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidationErrorKind;
+
+    fn error_kind(value: &InputMoneyValue) -> Option<ValidationErrorKind> {
+        value.get_validation_error().map(|err| err.get_kind())
+    }
+
+    #[test]
+    fn validates_bounds_and_step() {
+        let mut value = InputMoneyValue::new(MoneyAmount::new(1000, 2).unwrap(), "USD")
+            .set_min_value(MoneyAmount::new(5, 0).unwrap())
+            .set_max_value(MoneyAmount::new(100, 0).unwrap())
+            .set_step(MoneyAmount::new(50, 2).unwrap());
+
+        assert_eq!(value.as_str(), "10.00");
+        assert_eq!(error_kind(&value), None);
+
+        value.set_string_value("4.5".to_string());
+        assert_eq!(error_kind(&value), Some(ValidationErrorKind::MinValue));
+
+        value.set_string_value("10.25".to_string());
+        assert_eq!(
+            value.get_validation_error().unwrap().get_message(),
+            "must be a multiple of 0.50"
+        );
+
+        value.set_string_value("10.255".to_string());
+        assert_eq!(error_kind(&value), Some(ValidationErrorKind::Precision));
+
+        value.set_string_value("100.5".to_string());
+        assert_eq!(error_kind(&value), Some(ValidationErrorKind::MaxValue));
+        assert_eq!(value.format_on_blur().as_deref(), Some("100.50"));
+    }
+
+    #[test]
+    fn rejects_over_precision_input() {
+        let value = InputMoneyValue::empty("EUR", 2);
+
+        assert_eq!(value.get_currency_symbol(), "€");
+        assert!(value.accepts_input(""));
+        assert!(value.accepts_input("-12,3"));
+        assert!(value.accepts_input("12."));
+        assert!(!value.accepts_input("12.345"));
+        assert!(!value.accepts_input("1a"));
+        assert!(!InputMoneyValue::empty("JPY", 0).accepts_input("1."));
+    }
+}
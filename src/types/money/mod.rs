@@ -0,0 +1,6 @@
+mod money_amount;
+pub use money_amount::*;
+mod currency;
+pub use currency::*;
+mod input_money_value;
+pub use input_money_value::*;
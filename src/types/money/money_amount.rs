@@ -0,0 +1,250 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::types::ValidationError;
+
+/// Biggest scale whose divider fits the minor units: `10^18` is the last power of ten
+/// below `i64::MAX`.
+pub const MAX_MONEY_SCALE: u8 = 18;
+
+/// Fixed scale decimal stored as integer minor units, e.g. `12.34` with scale `2`
+/// is stored as `1234`. Never goes through `f64`, so balances and prices keep
+/// exactly the digits the user has typed.
+#[derive(Debug, Clone, Copy)]
+pub struct MoneyAmount {
+    minor_units: i64,
+    scale: u8,
+}
+
+impl MoneyAmount {
+    /// Fails for a scale bigger than [`MAX_MONEY_SCALE`].
+    pub fn new(minor_units: i64, scale: u8) -> Result<Self, ValidationError> {
+        if scale > MAX_MONEY_SCALE {
+            return Err(ValidationError::precision(MAX_MONEY_SCALE));
+        }
+
+        Ok(Self { minor_units, scale })
+    }
+
+    pub fn zero(scale: u8) -> Result<Self, ValidationError> {
+        Self::new(0, scale)
+    }
+
+    /// Accepts both `.` and `,` as decimal separator. Trailing zeros beyond the scale
+    /// are accepted, any other extra fraction digit is a precision violation.
+    pub fn parse(src: &str, scale: u8) -> Result<Self, ValidationError> {
+        if scale > MAX_MONEY_SCALE {
+            return Err(ValidationError::precision(MAX_MONEY_SCALE));
+        }
+
+        let src = src.trim();
+
+        if src.is_empty() {
+            return Err(ValidationError::empty());
+        }
+
+        let (negative, digits) = match src.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, src),
+        };
+
+        let (int_part, frac_part) = match digits.split_once(['.', ',']) {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (digits, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(ValidationError::illegal_chars());
+        }
+
+        if !int_part.chars().all(|c| c.is_ascii_digit())
+            || !frac_part.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(ValidationError::illegal_chars());
+        }
+
+        let scale_len = scale as usize;
+
+        if frac_part.len() > scale_len && frac_part[scale_len..].chars().any(|c| c != '0') {
+            return Err(ValidationError::precision(scale));
+        }
+
+        let mut minor_units: i64 = 0;
+
+        let frac_digits = frac_part
+            .chars()
+            .chain(std::iter::repeat('0'))
+            .take(scale_len);
+
+        for c in int_part.chars().chain(frac_digits) {
+            let digit = c as i64 - '0' as i64;
+            minor_units = minor_units
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(digit))
+                .ok_or_else(ValidationError::illegal_chars)?;
+        }
+
+        if negative {
+            minor_units = -minor_units;
+        }
+
+        Self::new(minor_units, scale)
+    }
+
+    pub fn get_minor_units(&self) -> i64 {
+        self.minor_units
+    }
+
+    pub fn get_scale(&self) -> u8 {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.minor_units < 0
+    }
+
+    /// Minor units expressed in the given (bigger or equal) scale. Both scales are at most
+    /// [`MAX_MONEY_SCALE`], so the result stays far below `i128::MAX`.
+    fn to_scale(self, scale: u8) -> i128 {
+        10i128
+            .checked_pow(u32::from(scale - self.scale))
+            .and_then(|multiplier| i128::from(self.minor_units).checked_mul(multiplier))
+            .expect("scale of MoneyAmount is at most MAX_MONEY_SCALE")
+    }
+
+    /// `true` if `self - base` is a whole number of `step`s.
+    pub fn is_multiple_of(&self, step: &MoneyAmount, base: &MoneyAmount) -> bool {
+        let scale = self.scale.max(step.scale).max(base.scale);
+        let step = step.to_scale(scale);
+
+        if step == 0 {
+            return true;
+        }
+
+        match self.to_scale(scale).checked_sub(base.to_scale(scale)) {
+            Some(diff) => diff % step == 0,
+            None => false,
+        }
+    }
+}
+
+impl PartialEq for MoneyAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MoneyAmount {}
+
+impl PartialOrd for MoneyAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MoneyAmount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.to_scale(scale).cmp(&other.to_scale(scale))
+    }
+}
+
+impl Display for MoneyAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let abs = self.minor_units.unsigned_abs();
+
+        if self.scale == 0 {
+            return write!(f, "{}{}", sign, abs);
+        }
+
+        let Some(divider) = 10u64.checked_pow(u32::from(self.scale)) else {
+            return Err(std::fmt::Error);
+        };
+
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            abs / divider,
+            abs % divider,
+            width = self.scale as usize
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidationErrorKind;
+
+    #[test]
+    fn parses_without_float_rounding() {
+        let amount = MoneyAmount::parse("0,1", 2).unwrap();
+        assert_eq!(amount.get_minor_units(), 10);
+        assert_eq!(amount.to_string(), "0.10");
+
+        let amount = MoneyAmount::parse("-1234.5600", 2).unwrap();
+        assert_eq!(amount.get_minor_units(), -123456);
+        assert_eq!(amount.to_string(), "-1234.56");
+
+        assert_eq!(MoneyAmount::parse("-.5", 1).unwrap().to_string(), "-0.5");
+    }
+
+    #[test]
+    fn rejects_over_precision_and_garbage() {
+        assert_eq!(
+            MoneyAmount::parse("1.234", 2).unwrap_err().get_kind(),
+            ValidationErrorKind::Precision
+        );
+        assert_eq!(
+            MoneyAmount::parse("1e3", 2).unwrap_err().get_kind(),
+            ValidationErrorKind::IllegalChars
+        );
+        assert_eq!(
+            MoneyAmount::parse("99999999999999999999", 2)
+                .unwrap_err()
+                .get_kind(),
+            ValidationErrorKind::IllegalChars
+        );
+    }
+
+    #[test]
+    fn compares_and_checks_step_across_scales() {
+        let amount = MoneyAmount::parse("1.50", 2).unwrap();
+        assert_eq!(amount, MoneyAmount::new(15, 1).unwrap());
+        assert!(amount < MoneyAmount::new(2, 0).unwrap());
+
+        let step = MoneyAmount::new(25, 2).unwrap();
+        let zero = MoneyAmount::zero(0).unwrap();
+        assert!(amount.is_multiple_of(&step, &zero));
+        assert!(
+            !MoneyAmount::new(160, 2)
+                .unwrap()
+                .is_multiple_of(&step, &zero)
+        );
+    }
+
+    #[test]
+    fn rejects_scale_over_max() {
+        assert_eq!(
+            MoneyAmount::new(1, MAX_MONEY_SCALE + 1)
+                .unwrap_err()
+                .get_kind(),
+            ValidationErrorKind::Precision
+        );
+        assert!(MoneyAmount::parse("1", 25).is_err());
+        assert!(MoneyAmount::zero(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn handles_extremes_of_max_scale() {
+        let min = MoneyAmount::new(i64::MIN, MAX_MONEY_SCALE).unwrap();
+        assert_eq!(min.to_string(), "-9.223372036854775808");
+
+        let max = MoneyAmount::new(i64::MAX, 0).unwrap();
+        assert!(min < max);
+
+        let step = MoneyAmount::new(1, MAX_MONEY_SCALE).unwrap();
+        assert!(max.is_multiple_of(&step, &min));
+    }
+}
@@ -0,0 +1,8 @@
+mod selection_limits;
+use selection_limits::*;
+mod multi_select_value;
+pub use multi_select_value::*;
+mod selected_values;
+pub use selected_values::*;
+mod select_enum_values;
+pub use select_enum_values::*;
@@ -0,0 +1,26 @@
+use crate::types::DetailedValidator;
+
+/// Item of a multi-select value as components render it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiSelectOption {
+    pub id: String,
+    pub label: String,
+    pub group: &'static str,
+    pub selected: bool,
+}
+
+/// Value rendered by the multi-select components: `select_chips`, `checkbox_list`
+/// and `dual_list_select`. Components emit the ids of the new selection.
+pub trait MultiSelectValue: DetailedValidator {
+    /// All items in display order.
+    fn get_options(&self) -> Vec<MultiSelectOption>;
+
+    /// Ids of the selected items in the order they were picked.
+    fn get_selected_ids(&self) -> Vec<String>;
+
+    /// `false` once the max amount is selected: components stop offering more items.
+    fn can_select_more(&self) -> bool;
+
+    /// Max amount of selected items, `None` if unlimited.
+    fn get_max_selected(&self) -> Option<usize>;
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::i18n::{MessageKey, Translator};
+use crate::types::*;
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+use rust_extensions::AsStr;
+
+/// Several variants of an enum, e.g. the time offsets a schedule runs in.
+#[derive(Debug, Clone)]
+pub struct SelectEnumValues<
+    TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>,
+> {
+    init_value: Vec<TItem>,
+    selected: Vec<TItem>,
+    limits: SelectionLimits,
+    touched: bool,
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> Default
+    for SelectEnumValues<TItem>
+{
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>>
+    SelectEnumValues<TItem>
+{
+    pub fn new(selected: Vec<TItem>) -> Self {
+        Self {
+            init_value: selected.clone(),
+            selected,
+            limits: SelectionLimits::default(),
+            touched: false,
+        }
+    }
+
+    pub fn set_min_selected(mut self, min: usize) -> Self {
+        self.limits.min = Some(min);
+        self
+    }
+
+    pub fn set_max_selected(mut self, max: usize) -> Self {
+        self.limits.max = Some(max);
+        self
+    }
+
+    pub fn is_selected(&self, item: &TItem) -> bool {
+        self.selected.contains(item)
+    }
+
+    pub fn get_value(&self) -> &[TItem] {
+        self.selected.as_slice()
+    }
+
+    pub fn set_value(&mut self, value: Vec<TItem>) {
+        self.selected = value;
+        self.touched = true;
+    }
+
+    /// Replaces the selection with the items emitted by a multi-select component.
+    pub fn set_selected_ids(&mut self, ids: &[String]) {
+        let value = ids
+            .iter()
+            .filter_map(|id| {
+                TItem::get_all()
+                    .iter()
+                    .find(|item| item.as_str() == id.as_str())
+            })
+            .cloned()
+            .collect();
+
+        self.set_value(value);
+    }
+
+    pub fn validate_detailed(&self) -> Result<(), ValidationError> {
+        self.limits.validate(self.selected.len())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.selected.len() != self.init_value.len()
+            || self
+                .selected
+                .iter()
+                .any(|item| !self.init_value.contains(item))
+    }
+
+    pub fn reset(&mut self) {
+        self.selected = self.init_value.clone();
+        self.touched = false;
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn mark_touched(&mut self) {
+        self.touched = true;
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> ValueValidator
+    for SelectEnumValues<TItem>
+{
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        self.validate_detailed()
+            .map_err(|err| err.to_value_validation_result())
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> DetailedValidator
+    for SelectEnumValues<TItem>
+{
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_detailed().err()
+    }
+
+    fn is_required(&self) -> bool {
+        self.limits.is_required()
+    }
+
+    fn is_touched(&self) -> bool {
+        self.is_touched()
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> FormField
+    for SelectEnumValues<TItem>
+{
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    fn mark_touched(&mut self) {
+        self.mark_touched();
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> MultiSelectValue
+    for SelectEnumValues<TItem>
+{
+    fn get_options(&self) -> Vec<MultiSelectOption> {
+        let translator = Translator::current();
+
+        TItem::get_all()
+            .iter()
+            .map(|item| MultiSelectOption {
+                id: item.as_str().to_string(),
+                label: translator.translate(&MessageKey::enum_value::<TItem>(item.as_str())),
+                group: "",
+                selected: self.is_selected(item),
+            })
+            .collect()
+    }
+
+    fn get_selected_ids(&self) -> Vec<String> {
+        self.selected
+            .iter()
+            .map(|item| item.as_str().to_string())
+            .collect()
+    }
+
+    fn can_select_more(&self) -> bool {
+        self.limits.can_select_more(self.selected.len())
+    }
+
+    fn get_max_selected(&self) -> Option<usize> {
+        self.limits.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_ids_to_variants() {
+        let mut value = SelectEnumValues::new(vec![TimeOffset::Utc]).set_max_selected(1);
+        assert!(!value.can_select_more());
+
+        value.set_selected_ids(&["+02:00".to_string(), "unknown".to_string()]);
+        assert_eq!(value.get_value(), &[TimeOffset::UtcPlus2]);
+        assert!(value.is_dirty());
+        assert!(value.is_touched());
+
+        value.reset();
+        assert!(!value.is_dirty());
+        assert_eq!(value.get_selected_ids(), vec!["+00:00"]);
+    }
+}
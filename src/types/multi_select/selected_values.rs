@@ -0,0 +1,236 @@
+use super::*;
+use crate::types::*;
+use rust_common::validators::{ValueValidationResult, ValueValidator};
+
+/// Several items picked from the groups of a select, e.g. accounts a report covers.
+pub struct SelectedValues<TValue: SelectValue + Clone> {
+    groups: Vec<SelectItemsGroup<TValue>>,
+    init_ids: Vec<String>,
+    selected: Vec<TValue>,
+    limits: SelectionLimits,
+    touched: bool,
+}
+
+impl<TValue: SelectValue + Clone> SelectedValues<TValue> {
+    pub fn new(groups: Vec<SelectItemsGroup<TValue>>) -> Self {
+        Self {
+            groups,
+            init_ids: Vec::new(),
+            selected: Vec::new(),
+            limits: SelectionLimits::default(),
+            touched: false,
+        }
+    }
+
+    /// Unknown ids are skipped. The selection becomes the baseline of [`FormField::is_dirty`].
+    pub fn new_with_selected(groups: Vec<SelectItemsGroup<TValue>>, ids: &[&str]) -> Self {
+        let mut result = Self::new(groups);
+        result.selected = result.find_all(ids.iter().copied());
+        result.init_ids = result.get_selected_ids();
+        result
+    }
+
+    pub fn set_min_selected(mut self, min: usize) -> Self {
+        self.limits.min = Some(min);
+        self
+    }
+
+    pub fn set_max_selected(mut self, max: usize) -> Self {
+        self.limits.max = Some(max);
+        self
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&TValue> {
+        self.groups
+            .iter()
+            .flat_map(|group| group.values.iter())
+            .find(|item| item.get_id() == id)
+    }
+
+    pub fn is_selected(&self, id: &str) -> bool {
+        self.selected.iter().any(|item| item.get_id() == id)
+    }
+
+    pub fn get_selected(&self) -> &[TValue] {
+        self.selected.as_slice()
+    }
+
+    pub fn get_selected_ids(&self) -> Vec<String> {
+        self.selected
+            .iter()
+            .map(|item| item.get_id().to_string())
+            .collect()
+    }
+
+    /// Replaces the selection with the items emitted by a multi-select component.
+    pub fn set_selected_ids(&mut self, ids: &[String]) {
+        self.selected = self.find_all(ids.iter().map(|id| id.as_str()));
+        self.touched = true;
+    }
+
+    pub fn get_items(&self) -> &[SelectItemsGroup<TValue>] {
+        self.groups.as_slice()
+    }
+
+    pub fn validate_detailed(&self) -> Result<(), ValidationError> {
+        self.limits.validate(self.selected.len())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        let mut ids = self.get_selected_ids();
+        ids.sort();
+
+        let mut init_ids = self.init_ids.clone();
+        init_ids.sort();
+
+        ids != init_ids
+    }
+
+    pub fn reset(&mut self) {
+        let init_ids = std::mem::take(&mut self.init_ids);
+        self.selected = self.find_all(init_ids.iter().map(|id| id.as_str()));
+        self.init_ids = init_ids;
+        self.touched = false;
+    }
+
+    pub fn is_touched(&self) -> bool {
+        self.touched
+    }
+
+    pub fn mark_touched(&mut self) {
+        self.touched = true;
+    }
+
+    fn find_all<'s>(&self, ids: impl Iterator<Item = &'s str>) -> Vec<TValue> {
+        let mut result: Vec<TValue> = Vec::new();
+
+        for id in ids {
+            if result.iter().any(|item| item.get_id() == id) {
+                continue;
+            }
+
+            if let Some(item) = self.find_by_id(id) {
+                result.push(item.clone());
+            }
+        }
+
+        result
+    }
+}
+
+impl<TValue: SelectValue + Clone> ValueValidator for SelectedValues<TValue> {
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        self.validate_detailed()
+            .map_err(|err| err.to_value_validation_result())
+    }
+}
+
+impl<TValue: SelectValue + Clone> DetailedValidator for SelectedValues<TValue> {
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_detailed().err()
+    }
+
+    fn is_required(&self) -> bool {
+        self.limits.is_required()
+    }
+
+    fn is_touched(&self) -> bool {
+        self.is_touched()
+    }
+}
+
+impl<TValue: SelectValue + Clone> FormField for SelectedValues<TValue> {
+    fn is_dirty(&self) -> bool {
+        self.is_dirty()
+    }
+
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    fn mark_touched(&mut self) {
+        self.mark_touched();
+    }
+}
+
+impl<TValue: SelectValue + Clone> MultiSelectValue for SelectedValues<TValue> {
+    fn get_options(&self) -> Vec<MultiSelectOption> {
+        self.groups
+            .iter()
+            .flat_map(|group| {
+                group.values.iter().map(|item| MultiSelectOption {
+                    id: item.get_id().to_string(),
+                    label: item.get_value().to_string(),
+                    group: group.name,
+                    selected: self.is_selected(item.get_id()),
+                })
+            })
+            .collect()
+    }
+
+    fn get_selected_ids(&self) -> Vec<String> {
+        self.get_selected_ids()
+    }
+
+    fn can_select_more(&self) -> bool {
+        self.limits.can_select_more(self.selected.len())
+    }
+
+    fn get_max_selected(&self) -> Option<usize> {
+        self.limits.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::*;
+
+    fn create_value() -> SelectedValues<Account> {
+        SelectedValues::new_with_selected(
+            vec![create_accounts(&["L1", "L2", "D1"]).into()],
+            &["L2"],
+        )
+        .set_min_selected(1)
+        .set_max_selected(2)
+    }
+
+    #[test]
+    fn tracks_selection_against_baseline() {
+        let mut value = create_value();
+        assert!(!value.is_dirty());
+        assert!(!value.is_touched());
+
+        value.set_selected_ids(&["D1".to_string(), "L2".to_string(), "XX".to_string()]);
+        assert_eq!(value.get_selected_ids(), vec!["D1", "L2"]);
+        assert!(value.is_dirty());
+        assert!(!value.can_select_more());
+
+        value.set_selected_ids(&["L2".to_string()]);
+        assert!(!value.is_dirty());
+
+        value.set_selected_ids(&[]);
+        value.reset();
+        assert_eq!(value.get_selected_ids(), vec!["L2"]);
+        assert!(!value.is_touched());
+    }
+
+    #[test]
+    fn validates_min_and_max_selected() {
+        let mut value = create_value();
+        assert!(value.validate_value().is_ok());
+
+        value.set_selected_ids(&[]);
+        assert!(matches!(
+            value.validate_value(),
+            Err(ValueValidationResult::Empty)
+        ));
+
+        let ids = ["L1", "L2", "D1"].map(|id| id.to_string());
+        value.set_selected_ids(&ids);
+        assert_eq!(
+            value.get_validation_error().unwrap().get_kind(),
+            ValidationErrorKind::MaxSelected
+        );
+    }
+}
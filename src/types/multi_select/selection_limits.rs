@@ -0,0 +1,75 @@
+use crate::types::ValidationError;
+
+/// Bounds of the amount of selected items of a multi-select value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct SelectionLimits {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+impl SelectionLimits {
+    /// Nothing selected below the minimum is reported as [`ValidationError::empty`],
+    /// so it is shown the same way as a blank required input.
+    pub fn validate(&self, amount: usize) -> Result<(), ValidationError> {
+        if let Some(min) = self.min
+            && amount < min
+        {
+            if amount == 0 {
+                return Err(ValidationError::empty());
+            }
+
+            return Err(ValidationError::min_selected(min));
+        }
+
+        if let Some(max) = self.max
+            && amount > max
+        {
+            return Err(ValidationError::max_selected(max));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.min.unwrap_or_default() > 0
+    }
+
+    pub fn can_select_more(&self, amount: usize) -> bool {
+        match self.max {
+            Some(max) => amount < max,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ValidationErrorKind;
+
+    #[test]
+    fn validates_amount_of_selected_items() {
+        let limits = SelectionLimits {
+            min: Some(2),
+            max: Some(3),
+        };
+
+        assert_eq!(
+            limits.validate(0).unwrap_err().get_kind(),
+            ValidationErrorKind::Empty
+        );
+
+        let err = limits.validate(1).unwrap_err();
+        assert_eq!(err.get_kind(), ValidationErrorKind::MinSelected);
+        assert_eq!(err.get_message(), "select at least 2");
+
+        assert!(limits.validate(3).is_ok());
+        assert!(!limits.can_select_more(3));
+
+        let err = limits.validate(4).unwrap_err();
+        assert_eq!(err.get_message(), "select at most 3");
+
+        assert!(SelectionLimits::default().validate(0).is_ok());
+        assert!(!SelectionLimits::default().is_required());
+    }
+}
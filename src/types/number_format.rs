@@ -0,0 +1,180 @@
+/// Separators and precision used to parse what the user typed into a number
+/// and to format the stored number back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    precision: Option<usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: None,
+            precision: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new(decimal_separator: char, thousands_separator: Option<char>) -> Self {
+        Self {
+            decimal_separator,
+            thousands_separator,
+            precision: None,
+        }
+    }
+
+    /// `1,234.56`
+    pub fn english() -> Self {
+        Self::new('.', Some(','))
+    }
+
+    /// `1 234,56`
+    pub fn european() -> Self {
+        Self::new(',', Some(' '))
+    }
+
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn get_precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    pub(crate) fn without_precision(mut self) -> Self {
+        self.precision = None;
+        self
+    }
+
+    /// Converts user input into the representation accepted by `FromStr` of numeric types.
+    /// Whitespace is always ignored, so `1 234,56` and `1 234,56` are both accepted.
+    pub fn normalize(&self, src: &str) -> String {
+        let mut result = String::with_capacity(src.len());
+
+        for c in src.chars() {
+            if c.is_whitespace() || Some(c) == self.thousands_separator {
+                continue;
+            }
+
+            if c == self.decimal_separator {
+                result.push('.');
+            } else {
+                result.push(c);
+            }
+        }
+
+        result
+    }
+
+    /// Formats the `Display` output of a number, e.g. `-1234.5` into `-1 234,50`.
+    pub fn format(&self, src: &str) -> String {
+        let (sign, digits) = match src.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", src),
+        };
+
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((int_part, frac_part)) => (int_part.to_string(), frac_part.to_string()),
+            None => (digits.to_string(), String::new()),
+        };
+
+        let (int_part, frac_part) = match self.precision {
+            Some(precision) => round_digits(int_part, frac_part, precision),
+            None => (int_part, frac_part),
+        };
+
+        let mut result = String::with_capacity(src.len() + int_part.len() / 3 + 1);
+        result.push_str(sign);
+
+        let int_len = int_part.len();
+        for (index, c) in int_part.chars().enumerate() {
+            if index > 0
+                && (int_len - index) % 3 == 0
+                && let Some(thousands_separator) = self.thousands_separator
+            {
+                result.push(thousands_separator);
+            }
+            result.push(c);
+        }
+
+        if !frac_part.is_empty() {
+            result.push(self.decimal_separator);
+            result.push_str(frac_part.as_str());
+        }
+
+        result
+    }
+}
+
+fn round_digits(int_part: String, mut frac_part: String, precision: usize) -> (String, String) {
+    if frac_part.len() <= precision {
+        while frac_part.len() < precision {
+            frac_part.push('0');
+        }
+        return (int_part, frac_part);
+    }
+
+    let round_up = frac_part.as_bytes()[precision] >= b'5';
+    frac_part.truncate(precision);
+
+    if !round_up {
+        return (int_part, frac_part);
+    }
+
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part.bytes()).collect();
+    let mut index = digits.len();
+    let mut carry = true;
+
+    while carry && index > 0 {
+        index -= 1;
+        if digits[index] == b'9' {
+            digits[index] = b'0';
+        } else {
+            digits[index] += 1;
+            carry = false;
+        }
+    }
+
+    if carry {
+        digits.insert(0, b'1');
+    }
+
+    let frac_start = digits.len() - precision;
+    let frac_part = String::from_utf8(digits[frac_start..].to_vec()).unwrap();
+    let int_part = String::from_utf8(digits[..frac_start].to_vec()).unwrap();
+
+    (int_part, frac_part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_european_input() {
+        let format = NumberFormat::european();
+        assert_eq!(format.normalize("1 234,56"), "1234.56");
+        assert_eq!(format.normalize("1\u{a0}234,56"), "1234.56");
+    }
+
+    #[test]
+    fn normalizes_english_input() {
+        let format = NumberFormat::english();
+        assert_eq!(format.normalize("1,234.56"), "1234.56");
+    }
+
+    #[test]
+    fn formats_with_separators_and_precision() {
+        let format = NumberFormat::european().with_precision(2);
+        assert_eq!(format.format("1234567.5"), "1 234 567,50");
+        assert_eq!(format.format("-999.999"), "-1 000,00");
+        assert_eq!(format.format("12"), "12,00");
+
+        let format = NumberFormat::english();
+        assert_eq!(format.format("1234.5678"), "1,234.5678");
+    }
+}
@@ -1,8 +1,10 @@
 use super::*;
+use rust_common::validators::{ValueValidationResult, ValueValidator};
 use rust_extensions::AsStr;
 
 #[derive(Debug, Clone)]
 pub struct SelectEnumValueOpt<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> {
+    init_value: Option<TItem>,
     selected: Option<TItem>,
     allow_null_result: bool,
 }
@@ -12,6 +14,7 @@ impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> Default
 {
     fn default() -> Self {
         Self {
+            init_value: Default::default(),
             selected: Default::default(),
             allow_null_result: true,
         }
@@ -21,6 +24,7 @@ impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> Default
 impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> SelectEnumValueOpt<TItem> {
     pub fn new(item: Option<TItem>) -> Self {
         Self {
+            init_value: item.clone(),
             selected: item,
             allow_null_result: true,
         }
@@ -31,6 +35,10 @@ impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> SelectEnumVal
         self
     }
 
+    pub fn is_required(&self) -> bool {
+        !self.allow_null_result
+    }
+
     pub fn validate(&self) -> bool {
         if self.allow_null_result {
             return true;
@@ -47,3 +55,27 @@ impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> SelectEnumVal
         self.selected = value;
     }
 }
+
+impl<TItem: AsStr + Clone + 'static + EnumIterator<TItem = TItem>> ValueValidator
+    for SelectEnumValueOpt<TItem>
+{
+    fn validate_value(&self) -> Result<(), ValueValidationResult> {
+        if self.validate() {
+            Ok(())
+        } else {
+            Err(ValueValidationResult::Empty)
+        }
+    }
+}
+
+impl<TItem: AsStr + Clone + PartialEq + 'static + EnumIterator<TItem = TItem>> FormField
+    for SelectEnumValueOpt<TItem>
+{
+    fn is_dirty(&self) -> bool {
+        self.init_value != self.selected
+    }
+
+    fn reset(&mut self) {
+        self.selected = self.init_value.clone();
+    }
+}
@@ -1,5 +1,8 @@
 use super::*;
 use rust_common::object_id::{IdExtension, ObjectId};
+use std::rc::Rc;
+
+#[derive(Clone)]
 pub struct SelectItemsGroup<TValue: SelectValue> {
     pub name: &'static str,
     pub values: Vec<TValue>,
@@ -12,15 +15,18 @@ impl<TValue: SelectValue> SelectItemsGroup<TValue> {
 }
 
 pub struct SelectedValueOpt<TValue: SelectValue + Clone> {
-    groups: Vec<SelectItemsGroup<TValue>>,
+    /// Shared with rendered components, which rebuild their options only when it changes.
+    groups: Rc<Vec<SelectItemsGroup<TValue>>>,
     selected: Option<TValue>,
+    allow_null_result: bool,
 }
 
 impl<TValue: SelectValue + Clone> Default for SelectedValueOpt<TValue> {
     fn default() -> Self {
         Self {
-            groups: Vec::new(),
+            groups: Rc::default(),
             selected: None,
+            allow_null_result: true,
         }
     }
 }
@@ -28,8 +34,9 @@ impl<TValue: SelectValue + Clone> Default for SelectedValueOpt<TValue> {
 impl<TValue: SelectValue + Clone> SelectedValueOpt<TValue> {
     pub fn new(groups: Vec<SelectItemsGroup<TValue>>) -> Self {
         let result = Self {
-            groups,
+            groups: Rc::new(groups),
             selected: None,
+            allow_null_result: true,
         };
 
         result
@@ -49,55 +56,75 @@ impl<TValue: SelectValue + Clone> SelectedValueOpt<TValue> {
         result
     }
 
+    /// `false` makes the value required: select components do not offer "Not selected" then.
+    pub fn allow_null_result(mut self, value: bool) -> Self {
+        self.allow_null_result = value;
+        self
+    }
+
+    pub fn is_required(&self) -> bool {
+        !self.allow_null_result
+    }
+
     pub fn set_selected(&mut self, id: Option<&str>) {
         let Some(id) = id else {
             self.selected = None;
             return;
         };
 
-        for group in self.groups.iter() {
-            for item in group.values.iter() {
-                if item.get_id() == id {
-                    self.selected = Some(item.clone());
-                    return;
-                }
-            }
+        if let Some(item) = self.find_by_id(id) {
+            self.selected = Some(item.clone());
         }
     }
 
+    pub fn find_by_id(&self, id: &str) -> Option<&TValue> {
+        self.groups
+            .iter()
+            .flat_map(|group| group.values.iter())
+            .find(|item| item.get_id() == id)
+    }
+
     pub fn get_selected(&self) -> Option<&TValue> {
         self.selected.as_ref()
     }
 
     pub fn push(&mut self, group: impl Into<SelectItemsGroup<TValue>>) {
         let group = group.into();
-        self.groups.push(group);
+        Rc::make_mut(&mut self.groups).push(group);
     }
 
     pub fn push_item(&mut self, value: TValue) {
-        if self.groups.len() == 0 {
-            self.groups.push(SelectItemsGroup {
+        let groups = Rc::make_mut(&mut self.groups);
+        if groups.len() == 0 {
+            groups.push(SelectItemsGroup {
                 name: "",
                 values: vec![value],
             });
         } else {
-            self.groups.get_mut(0).unwrap().values.push(value);
+            groups.get_mut(0).unwrap().values.push(value);
         }
     }
 
     pub fn get_items(&self) -> &[SelectItemsGroup<TValue>] {
         self.groups.as_slice()
     }
+
+    /// While a component keeps the returned items, changing them allocates new ones,
+    /// so comparing the pointers tells whether the items have changed.
+    pub(crate) fn get_shared_items(&self) -> Rc<Vec<SelectItemsGroup<TValue>>> {
+        self.groups.clone()
+    }
 }
 
 impl<TValue: SelectValue + Clone> Into<SelectedValueOpt<TValue>> for Vec<TValue> {
     fn into(self) -> SelectedValueOpt<TValue> {
         SelectedValueOpt {
-            groups: vec![SelectItemsGroup {
+            groups: Rc::new(vec![SelectItemsGroup {
                 name: "",
                 values: self,
-            }],
+            }]),
             selected: None,
+            allow_null_result: true,
         }
     }
 }
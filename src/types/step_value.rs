@@ -0,0 +1,80 @@
+/// Numeric types which `InputValue` can increment by a step.
+pub trait StepValue: Sized + Copy + PartialOrd + Default {
+    fn default_step() -> Self;
+
+    /// `self + step * steps`, or `None` on overflow.
+    fn add_steps(self, step: Self, steps: i32) -> Option<Self>;
+}
+
+macro_rules! impl_int_step_value {
+    ($($ty:ty),*) => {
+        $(
+            impl StepValue for $ty {
+                fn default_step() -> Self {
+                    1
+                }
+
+                fn add_steps(self, step: Self, steps: i32) -> Option<Self> {
+                    let count = Self::try_from(steps.unsigned_abs()).ok()?;
+                    let delta = step.checked_mul(count)?;
+
+                    if steps < 0 {
+                        self.checked_sub(delta)
+                    } else {
+                        self.checked_add(delta)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int_step_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_float_step_value {
+    ($($ty:ty),*) => {
+        $(
+            impl StepValue for $ty {
+                fn default_step() -> Self {
+                    1.0
+                }
+
+                /// Rounded to the decimal places of the value or the step, so `0.1 + 0.2`
+                /// gives `0.3` rather than `0.30000000000000004`.
+                fn add_steps(self, step: Self, steps: i32) -> Option<Self> {
+                    let result = self + step * steps as $ty;
+
+                    if !result.is_finite() {
+                        return None;
+                    }
+
+                    let decimals = get_decimals(&self.to_string()).max(get_decimals(&step.to_string()));
+                    format!("{:.*}", decimals, result).parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_float_step_value!(f32, f64);
+
+fn get_decimals(src: &str) -> usize {
+    match src.split_once('.') {
+        Some((_, frac_part)) => frac_part.len(),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_steps() {
+        assert_eq!(5i32.add_steps(2, -3), Some(-1));
+        assert_eq!(1u8.add_steps(1, -2), None);
+        assert_eq!(250u8.add_steps(1, 10), None);
+        assert_eq!(0.1f64.add_steps(0.2, 1), Some(0.3));
+        assert_eq!(1.5f64.add_steps(0.25, -10), Some(-1.0));
+    }
+}
@@ -218,6 +218,8 @@ impl TimeOffset {
 impl EnumIterator for TimeOffset {
     type TItem = Self;
 
+    const NAME: &'static str = "TimeOffset";
+
     fn get_value(&self) -> Self
     where
         Self: Sized,
@@ -0,0 +1,26 @@
+use rust_common::validators::ValueValidator;
+
+use super::ValidationError;
+
+/// Extends [`ValueValidator`] with a [`ValidationError`] carrying the violated bounds
+/// and the message of a failed rule, which components display to the user.
+pub trait DetailedValidator: ValueValidator {
+    fn get_validation_error(&self) -> Option<ValidationError> {
+        self.validate_value().err().map(ValidationError::from)
+    }
+
+    /// `true` while an async check of the current value is running.
+    fn is_validation_pending(&self) -> bool {
+        false
+    }
+
+    /// `true` if an empty value does not pass validation.
+    fn is_required(&self) -> bool {
+        false
+    }
+
+    /// `true` once the user has interacted with the value, see `InputValue::is_touched`.
+    fn is_touched(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,4 @@
+mod validation_error;
+pub use validation_error::*;
+mod detailed_validator;
+pub use detailed_validator::*;
@@ -0,0 +1,268 @@
+use std::{fmt::Display, rc::Rc};
+
+use rust_common::validators::ValueValidationResult;
+
+use crate::i18n::{MessageKey, Translator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    Empty,
+    IllegalChars,
+    MinValue,
+    MaxValue,
+    MinLength,
+    MaxLength,
+    NotAllowedChar,
+    Format,
+    Precision,
+    Step,
+    Incomplete,
+    MinSelected,
+    MaxSelected,
+    Pending,
+    Custom,
+}
+
+pub const PARAM_MIN: &str = "min";
+pub const PARAM_MAX: &str = "max";
+pub const PARAM_CHAR: &str = "char";
+pub const PARAM_EXPECTED: &str = "expected";
+pub const PARAM_SCALE: &str = "scale";
+pub const PARAM_STEP: &str = "step";
+
+/// Hook to override the message of a validation error. Returning `None` falls back
+/// to [`ValidationError::get_message`].
+pub type ValidationMessageFormatter = Rc<dyn Fn(&ValidationError) -> Option<String>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    kind: ValidationErrorKind,
+    params: Vec<(&'static str, String)>,
+    message: Option<String>,
+}
+
+impl ValidationError {
+    pub fn new(kind: ValidationErrorKind) -> Self {
+        Self {
+            kind,
+            params: Vec::new(),
+            message: None,
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self::new(ValidationErrorKind::Empty)
+    }
+
+    pub fn illegal_chars() -> Self {
+        Self::new(ValidationErrorKind::IllegalChars)
+    }
+
+    pub fn min_value(min: impl Display) -> Self {
+        Self::new(ValidationErrorKind::MinValue).with_param(PARAM_MIN, min)
+    }
+
+    pub fn max_value(max: impl Display) -> Self {
+        Self::new(ValidationErrorKind::MaxValue).with_param(PARAM_MAX, max)
+    }
+
+    pub fn min_length(min: usize) -> Self {
+        Self::new(ValidationErrorKind::MinLength).with_param(PARAM_MIN, min)
+    }
+
+    pub fn max_length(max: usize) -> Self {
+        Self::new(ValidationErrorKind::MaxLength).with_param(PARAM_MAX, max)
+    }
+
+    pub fn not_allowed_char(c: char) -> Self {
+        Self::new(ValidationErrorKind::NotAllowedChar).with_param(PARAM_CHAR, c)
+    }
+
+    pub fn format(expected: &'static str) -> Self {
+        Self::new(ValidationErrorKind::Format).with_param(PARAM_EXPECTED, expected)
+    }
+
+    pub fn precision(scale: u8) -> Self {
+        Self::new(ValidationErrorKind::Precision).with_param(PARAM_SCALE, scale)
+    }
+
+    pub fn step(step: impl Display) -> Self {
+        Self::new(ValidationErrorKind::Step).with_param(PARAM_STEP, step)
+    }
+
+    pub fn incomplete(expected: impl Display) -> Self {
+        Self::new(ValidationErrorKind::Incomplete).with_param(PARAM_EXPECTED, expected)
+    }
+
+    pub fn min_selected(min: usize) -> Self {
+        Self::new(ValidationErrorKind::MinSelected).with_param(PARAM_MIN, min)
+    }
+
+    pub fn max_selected(max: usize) -> Self {
+        Self::new(ValidationErrorKind::MaxSelected).with_param(PARAM_MAX, max)
+    }
+
+    /// The async check of the value has not finished yet.
+    pub fn pending() -> Self {
+        Self::new(ValidationErrorKind::Pending)
+    }
+
+    pub fn custom(message: impl Into<String>) -> Self {
+        Self::new(ValidationErrorKind::Custom).with_message(message)
+    }
+
+    pub fn with_param(mut self, name: &'static str, value: impl Display) -> Self {
+        self.params.push((name, value.to_string()));
+        self
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn get_kind(&self) -> ValidationErrorKind {
+        self.kind
+    }
+
+    pub fn get_param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param_name, _)| *param_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_custom_message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn get_message(&self) -> String {
+        if let Some(message) = self.message.as_ref() {
+            return message.clone();
+        }
+
+        match self.kind {
+            ValidationErrorKind::Empty => "required".to_string(),
+            ValidationErrorKind::IllegalChars => "invalid value".to_string(),
+            ValidationErrorKind::MinValue => match self.get_param(PARAM_MIN) {
+                Some(min) => format!("must be ≥ {}", min),
+                None => "min value violation".to_string(),
+            },
+            ValidationErrorKind::MaxValue => match self.get_param(PARAM_MAX) {
+                Some(max) => format!("must be ≤ {}", max),
+                None => "max value violation".to_string(),
+            },
+            ValidationErrorKind::MinLength => format!(
+                "must be at least {} characters",
+                self.get_param(PARAM_MIN).unwrap_or_default()
+            ),
+            ValidationErrorKind::MaxLength => format!(
+                "must be at most {} characters",
+                self.get_param(PARAM_MAX).unwrap_or_default()
+            ),
+            ValidationErrorKind::NotAllowedChar => format!(
+                "character '{}' is not allowed",
+                self.get_param(PARAM_CHAR).unwrap_or_default()
+            ),
+            ValidationErrorKind::Format => match self.get_param(PARAM_EXPECTED) {
+                Some(expected) => format!("invalid {}", expected),
+                None => "invalid format".to_string(),
+            },
+            ValidationErrorKind::Precision => match self.get_param(PARAM_SCALE) {
+                Some("0") => "must be a whole number".to_string(),
+                Some(scale) => format!("at most {} decimal places", scale),
+                None => "too many decimal places".to_string(),
+            },
+            ValidationErrorKind::Step => match self.get_param(PARAM_STEP) {
+                Some(step) => format!("must be a multiple of {}", step),
+                None => "step violation".to_string(),
+            },
+            ValidationErrorKind::Incomplete => match self.get_param(PARAM_EXPECTED) {
+                Some(expected) => format!("must match {}", expected),
+                None => "incomplete value".to_string(),
+            },
+            ValidationErrorKind::MinSelected => format!(
+                "select at least {}",
+                self.get_param(PARAM_MIN).unwrap_or_default()
+            ),
+            ValidationErrorKind::MaxSelected => format!(
+                "select at most {}",
+                self.get_param(PARAM_MAX).unwrap_or_default()
+            ),
+            ValidationErrorKind::Pending => "checking…".to_string(),
+            ValidationErrorKind::Custom => "invalid value".to_string(),
+        }
+    }
+
+    pub fn format_message(&self, formatter: Option<&ValidationMessageFormatter>) -> String {
+        if let Some(formatter) = formatter
+            && let Some(message) = formatter(self)
+        {
+            return message;
+        }
+
+        Translator::current().translate(&MessageKey::Validation(self))
+    }
+
+    pub fn to_value_validation_result(&self) -> ValueValidationResult {
+        match self.kind {
+            ValidationErrorKind::Empty => ValueValidationResult::Empty,
+            ValidationErrorKind::MinValue | ValidationErrorKind::MinSelected => {
+                ValueValidationResult::MinValueViolation
+            }
+            ValidationErrorKind::MaxValue | ValidationErrorKind::MaxSelected => {
+                ValueValidationResult::MaxValueViolation
+            }
+            _ => ValueValidationResult::IllegalChars,
+        }
+    }
+}
+
+impl From<ValueValidationResult> for ValidationError {
+    fn from(value: ValueValidationResult) -> Self {
+        match value {
+            ValueValidationResult::Empty => Self::empty(),
+            ValueValidationResult::IllegalChars => Self::illegal_chars(),
+            ValueValidationResult::MinValueViolation => Self::new(ValidationErrorKind::MinValue),
+            ValueValidationResult::MaxValueViolation => Self::new(ValidationErrorKind::MaxValue),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bounds_in_message() {
+        assert_eq!(ValidationError::min_value(10).get_message(), "must be ≥ 10");
+        assert_eq!(ValidationError::max_value(2.5).to_string(), "must be ≤ 2.5");
+        assert_eq!(
+            ValidationError::from(ValueValidationResult::MinValueViolation).get_message(),
+            "min value violation"
+        );
+    }
+
+    #[test]
+    fn formatter_overrides_message() {
+        let formatter: ValidationMessageFormatter = Rc::new(|err| match err.get_kind() {
+            ValidationErrorKind::MinValue => {
+                Some(format!("not less than {}", err.get_param(PARAM_MIN)?))
+            }
+            _ => None,
+        });
+
+        let err = ValidationError::min_value(5);
+        assert_eq!(err.format_message(Some(&formatter)), "not less than 5");
+        assert_eq!(
+            ValidationError::illegal_chars().format_message(Some(&formatter)),
+            "invalid value"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::net::IpAddr;
+
+use super::ValueRule;
+use crate::types::ValidationError;
+
+pub struct EmailRule;
+
+impl<T> ValueRule<T> for EmailRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        if is_email(raw) {
+            return Ok(());
+        }
+
+        Err(ValidationError::format("email"))
+    }
+}
+
+pub struct UrlRule;
+
+impl<T> ValueRule<T> for UrlRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        if is_url(raw) {
+            return Ok(());
+        }
+
+        Err(ValidationError::format("url"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpAddressRule {
+    Any,
+    V4,
+    V6,
+}
+
+impl<T> ValueRule<T> for IpAddressRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        let valid = match (self, raw.parse::<IpAddr>()) {
+            (Self::Any, Ok(_)) => true,
+            (Self::V4, Ok(ip)) => ip.is_ipv4(),
+            (Self::V6, Ok(ip)) => ip.is_ipv6(),
+            (_, Err(_)) => false,
+        };
+
+        if valid {
+            return Ok(());
+        }
+
+        Err(ValidationError::format("ip address"))
+    }
+}
+
+fn is_email(src: &str) -> bool {
+    if src.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let Some((local, domain)) = src.split_once('@') else {
+        return false;
+    };
+
+    if local.is_empty() || domain.contains('@') {
+        return false;
+    }
+
+    match domain.rsplit_once('.') {
+        Some((host, zone)) => !host.is_empty() && zone.len() >= 2,
+        None => false,
+    }
+}
+
+fn is_url(src: &str) -> bool {
+    if src.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let rest = match src.split_once("://") {
+        Some(("http", rest)) | Some(("https", rest)) => rest,
+        _ => return false,
+    };
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_email() {
+        assert!(is_email("trader@example.com"));
+        assert!(!is_email("trader@example"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("trader @example.com"));
+    }
+
+    #[test]
+    fn validates_url() {
+        assert!(is_url("https://example.com/path?a=1"));
+        assert!(!is_url("ftp://example.com"));
+        assert!(!is_url("https://"));
+    }
+}
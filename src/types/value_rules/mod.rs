@@ -0,0 +1,8 @@
+mod value_rule;
+pub use value_rule::*;
+mod value_rules_chain;
+pub use value_rules_chain::*;
+mod text_rules;
+pub use text_rules::*;
+mod format_rules;
+pub use format_rules::*;
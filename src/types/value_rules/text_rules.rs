@@ -0,0 +1,129 @@
+use regex::Regex;
+
+use super::ValueRule;
+use crate::types::ValidationError;
+
+pub struct RegexRule {
+    regex: Regex,
+    message: String,
+}
+
+impl RegexRule {
+    /// Fails on an invalid `pattern`, e.g. one configured by a user.
+    pub fn new(pattern: &str, message: impl Into<String>) -> Result<Self, regex::Error> {
+        let regex = Regex::new(pattern)?;
+        Ok(Self::from_regex(regex, message))
+    }
+
+    pub fn from_regex(regex: Regex, message: impl Into<String>) -> Self {
+        Self {
+            regex,
+            message: message.into(),
+        }
+    }
+}
+
+impl<T> ValueRule<T> for RegexRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        if self.regex.is_match(raw) {
+            return Ok(());
+        }
+
+        Err(ValidationError::custom(self.message.as_str()))
+    }
+}
+
+pub struct LengthRule {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl LengthRule {
+    pub fn new(min: Option<usize>, max: Option<usize>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn min(min: usize) -> Self {
+        Self::new(Some(min), None)
+    }
+
+    pub fn max(max: usize) -> Self {
+        Self::new(None, Some(max))
+    }
+}
+
+impl<T> ValueRule<T> for LengthRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        let len = raw.chars().count();
+
+        if let Some(min) = self.min
+            && len < min
+        {
+            return Err(ValidationError::min_length(min));
+        }
+
+        if let Some(max) = self.max
+            && len > max
+        {
+            return Err(ValidationError::max_length(max));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct AllowedCharsRule {
+    is_allowed: fn(char) -> bool,
+    extra_chars: &'static str,
+}
+
+impl AllowedCharsRule {
+    pub fn new(is_allowed: fn(char) -> bool) -> Self {
+        Self {
+            is_allowed,
+            extra_chars: "",
+        }
+    }
+
+    pub fn alphanumeric() -> Self {
+        Self::new(char::is_alphanumeric)
+    }
+
+    pub fn digits() -> Self {
+        Self::new(|c| c.is_ascii_digit())
+    }
+
+    pub fn with_extra_chars(mut self, extra_chars: &'static str) -> Self {
+        self.extra_chars = extra_chars;
+        self
+    }
+}
+
+impl<T> ValueRule<T> for AllowedCharsRule {
+    fn check(&self, raw: &str, _value: &T) -> Result<(), ValidationError> {
+        let not_allowed = raw
+            .chars()
+            .find(|c| !(self.is_allowed)(*c) && !self.extra_chars.contains(*c));
+
+        match not_allowed {
+            Some(c) => Err(ValidationError::not_allowed_char(c)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_pattern() {
+        assert!(RegexRule::new("[a-z", "letters only").is_err());
+
+        let rule = RegexRule::new("^[a-z]+$", "letters only").unwrap();
+        assert!(ValueRule::<String>::check(&rule, "abc", &String::new()).is_ok());
+
+        let err = ValueRule::<String>::check(&rule, "abc1", &String::new()).unwrap_err();
+        assert_eq!(err.get_message(), "letters only");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::types::ValidationError;
+
+/// Validation rule which runs after the raw string is parsed into `T`.
+pub trait ValueRule<T> {
+    fn check(&self, raw: &str, value: &T) -> Result<(), ValidationError>;
+}
+
+/// Closures return just the message which is reported as a custom error.
+impl<T, TFn: Fn(&T) -> Result<(), String>> ValueRule<T> for TFn {
+    fn check(&self, _raw: &str, value: &T) -> Result<(), ValidationError> {
+        self(value).map_err(ValidationError::custom)
+    }
+}
+
+/// Rule built from a predicate. The predicate may capture signals of other fields
+/// to implement cross-field checks.
+pub struct PredicateRule<T> {
+    predicate: Box<dyn Fn(&T) -> bool>,
+    message: String,
+}
+
+impl<T> PredicateRule<T> {
+    pub fn new(predicate: impl Fn(&T) -> bool + 'static, message: impl Into<String>) -> Self {
+        Self {
+            predicate: Box::new(predicate),
+            message: message.into(),
+        }
+    }
+}
+
+impl<T> ValueRule<T> for PredicateRule<T> {
+    fn check(&self, _raw: &str, value: &T) -> Result<(), ValidationError> {
+        if (self.predicate)(value) {
+            return Ok(());
+        }
+
+        Err(ValidationError::custom(self.message.as_str()))
+    }
+}
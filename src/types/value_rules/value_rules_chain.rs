@@ -0,0 +1,61 @@
+use std::{fmt::Debug, rc::Rc};
+
+use super::ValueRule;
+use crate::types::ValidationError;
+
+pub struct ValueRules<T> {
+    rules: Vec<Rc<dyn ValueRule<T>>>,
+}
+
+impl<T> ValueRules<T> {
+    pub fn push(&mut self, rule: impl ValueRule<T> + 'static) {
+        self.rules.push(Rc::new(rule));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn check(&self, raw: &str, value: &T) -> Result<(), ValidationError> {
+        for rule in self.rules.iter() {
+            rule.check(raw, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Default for ValueRules<T> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+impl<T> Clone for ValueRules<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for ValueRules<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules.len() == other.rules.len()
+            && self
+                .rules
+                .iter()
+                .zip(other.rules.iter())
+                .all(|(a, b)| Rc::ptr_eq(a, b))
+    }
+}
+
+impl<T> Eq for ValueRules<T> {}
+
+impl<T> Debug for ValueRules<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValueRules")
+            .field("amount", &self.rules.len())
+            .finish()
+    }
+}
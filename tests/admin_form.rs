@@ -0,0 +1,107 @@
+use dioxus_admin_ui_kit::AdminForm;
+use dioxus_admin_ui_kit::types::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct TraderDto {
+    name: String,
+    leverage: u32,
+    comment: Option<i64>,
+    offset: Option<TimeOffset>,
+    enabled: bool,
+    visible: bool,
+}
+
+#[derive(AdminForm)]
+#[admin_form(dto = TraderDto)]
+struct TraderForm {
+    #[admin_form(caption = "Name")]
+    name: InputValue<String>,
+    #[admin_form(caption = "Leverage")]
+    leverage: InputValue<u32>,
+    #[admin_form(caption = "Comment id", dto_field = "comment")]
+    comment_id: InputValueOpt<i64>,
+    #[admin_form(caption = "Time offset")]
+    offset: SelectEnumValueOpt<TimeOffset>,
+    #[admin_form(caption = "Enabled")]
+    enabled: BoolValue,
+    #[admin_form(caption = "Visible")]
+    visible: bool,
+}
+
+fn create_dto() -> TraderDto {
+    TraderDto {
+        name: "trader".to_string(),
+        leverage: 100,
+        comment: None,
+        offset: Some(TimeOffset::UtcPlus3),
+        enabled: true,
+        visible: false,
+    }
+}
+
+#[test]
+fn round_trips_dto() {
+    let dto = create_dto();
+    let form = TraderForm::new(&dto);
+
+    assert!(form.is_valid());
+    assert!(!form.as_form().is_dirty());
+    assert_eq!(form.to_dto().ok(), Some(dto));
+}
+
+#[test]
+fn reports_field_errors() {
+    let mut form = TraderForm::new(&create_dto());
+    form.leverage.set_string_value("abc".to_string());
+    form.enabled.set_value(false);
+
+    let errors = form.to_dto().err().expect("form must be invalid");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors.has_error("leverage"));
+
+    form.as_form_mut().reset();
+    assert!(form.is_valid());
+}
+
+#[test]
+fn tracks_bool_fields() {
+    let dto = create_dto();
+    let mut form = TraderForm::new(&dto);
+
+    form.enabled.set_value(false);
+    assert!(form.as_form().is_dirty());
+    assert!(form.as_form().can_be_submitted());
+    assert_eq!(form.to_dto().ok().map(|dto| dto.enabled), Some(false));
+
+    form.as_form_mut().reset();
+    assert!(!form.as_form().is_dirty());
+    assert_eq!(form.to_dto().ok(), Some(dto));
+}
+
+#[test]
+fn copies_plain_bool_fields() {
+    let mut form = TraderForm::new(&create_dto());
+
+    form.visible = true;
+
+    assert!(!form.as_form().is_dirty());
+    assert_eq!(form.to_dto().ok().map(|dto| dto.visible), Some(true));
+}
+
+#[test]
+fn marks_fields_touched_on_submit_attempt() {
+    let mut dto = create_dto();
+    dto.name = String::new();
+    let mut form = TraderForm::new(&dto);
+    assert!(!form.name.is_touched());
+
+    let result = form.attempt_submit(|dto| dto.name);
+
+    assert!(result.err().is_some_and(|errors| errors.has_error("name")));
+    assert!(form.name.is_touched());
+    assert!(form.comment_id.is_touched());
+
+    form.name.set_string_value("trader".to_string());
+    assert_eq!(form.try_to_dto().ok(), Some(create_dto()));
+}